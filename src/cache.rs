@@ -0,0 +1,218 @@
+use anyhow::{Context, Result};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::Project;
+use crate::utils::fsio::write_atomic;
+
+/// Bump when the entry layout changes; older caches are rebuilt
+const CACHE_VERSION: u32 = 2;
+
+/// Files modified this close to the last cache write are re-read regardless of
+/// mtime and size: on filesystems with coarse timestamps a same-size edit within
+/// one tick would otherwise go unnoticed.
+const RACY_WINDOW_NS: u64 = 2_000_000_000;
+
+/// `.membrane/index.json`: per-file summary of the projects directory.
+/// Entries are revalidated by mtime and size, so a stale cache is harmless.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProjectIndex {
+    pub version: u32,
+    /// When the cache was last written, in nanoseconds since the epoch
+    #[serde(default)]
+    pub written: u64,
+    /// Keyed by project name (file stem)
+    pub entries: IndexMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub id: Option<String>,
+    /// Modification time in nanoseconds since the epoch
+    pub mtime: u64,
+    pub size: u64,
+    /// All keys, in file order
+    pub keys: Vec<String>,
+    /// Scalar values only (enough for listings and sorting)
+    pub scalars: IndexMap<String, Value>,
+}
+
+impl CacheEntry {
+    fn from_project(project: &Project, mtime: u64, size: u64) -> Self {
+        Self {
+            id: project.get("_id").and_then(|v| v.as_str()).map(str::to_string),
+            mtime,
+            size,
+            keys: project.keys().cloned().collect(),
+            scalars: project
+                .iter()
+                .filter(|(_, v)| is_scalar(v))
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        }
+    }
+
+    /// True when `key` exists but holds a sequence or mapping
+    pub fn has_complex(&self, key: &str) -> bool {
+        self.keys.iter().any(|k| k == key) && !self.scalars.contains_key(key)
+    }
+
+    /// Modified too close to `written` for mtime and size to be trusted
+    fn is_racy(&self, written: u64) -> bool {
+        self.mtime.saturating_add(RACY_WINDOW_NS) >= written
+    }
+}
+
+// ------------------------------------------------------------
+// Public API
+// ------------------------------------------------------------
+
+pub fn cache_path(projects_dir: &Path) -> PathBuf {
+    projects_dir.with_file_name("index.json")
+}
+
+/// Load the cache and bring it up to date with the projects directory.
+/// Only files whose mtime or size changed (or that were modified around the
+/// last cache write) are re-parsed; the cache is only written when an entry changed.
+pub fn refresh(projects_dir: &Path) -> Result<ProjectIndex> {
+    let path = cache_path(projects_dir);
+
+    let mut index = fs::read_to_string(&path)
+        .ok()
+        .and_then(|s| serde_json::from_str::<ProjectIndex>(&s).ok())
+        .filter(|i| i.version == CACHE_VERSION)
+        .unwrap_or_else(|| ProjectIndex {
+            version: CACHE_VERSION,
+            written: 0,
+            entries: IndexMap::new(),
+        });
+
+    let now = now_ns();
+
+    let mut dirty = false;
+    let mut seen = Vec::new();
+
+    for entry in fs::read_dir(projects_dir)? {
+        let file = entry?.path();
+
+        if file.extension().and_then(|s| s.to_str()) != Some("yaml") {
+            continue;
+        }
+
+        let name = file
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let (mtime, size) = stat(&file)?;
+
+        let cached = index.entries.get(&name);
+        let fresh = cached
+            .map(|e| e.mtime == mtime && e.size == size && !e.is_racy(index.written))
+            .unwrap_or(false);
+
+        if !fresh {
+            let content = fs::read_to_string(&file)?;
+            let project: Project = serde_yaml::from_str(&content)
+                .with_context(|| format!("{} is not a valid project (try `me doctor`)", file.display()))?;
+
+            let entry = CacheEntry::from_project(&project, mtime, size);
+
+            // rewrite when the entry changed, or once it has settled and can be trusted
+            if cached != Some(&entry) || !entry.is_racy(now) {
+                index.entries.insert(name.clone(), entry);
+                dirty = true;
+            }
+        }
+
+        seen.push(name);
+    }
+
+    let before = index.entries.len();
+    index.entries.retain(|name, _| seen.contains(name));
+    dirty |= index.entries.len() != before;
+
+    if dirty {
+        index.entries.sort_keys();
+        index.written = now;
+        save(projects_dir, &index)?;
+    }
+
+    Ok(index)
+}
+
+/// Throw the cache away and rebuild it from every file
+pub fn rebuild(projects_dir: &Path) -> Result<ProjectIndex> {
+    let path = cache_path(projects_dir);
+    if path.exists() {
+        fs::remove_file(&path)?;
+    }
+    refresh(projects_dir)
+}
+
+/// Lightweight `(name, project)` pairs for list views, holding scalar keys only.
+/// Returns `None` when a full load is needed instead: `sort_key` holds a
+/// sequence or mapping somewhere, or a project still lacks an `_id`.
+pub fn listing(
+    projects_dir: &Path,
+    sort_key: Option<&str>,
+) -> Result<Option<Vec<(String, Project)>>> {
+    let index = refresh(projects_dir)?;
+
+    if index.entries.values().any(|e| e.id.is_none()) {
+        return Ok(None);
+    }
+
+    if let Some(k) = sort_key {
+        if index.entries.values().any(|e| e.has_complex(k)) {
+            return Ok(None);
+        }
+    }
+
+    // sequences and mappings are left out; only `sort_key` (checked above)
+    // needs them, e.g. for `--only`
+    Ok(Some(
+        index
+            .entries
+            .into_iter()
+            .map(|(name, e)| (name, e.scalars))
+            .collect(),
+    ))
+}
+
+// ------------------------------------------------------------
+// Internal
+// ------------------------------------------------------------
+
+fn save(projects_dir: &Path, index: &ProjectIndex) -> Result<()> {
+    write_atomic(&cache_path(projects_dir), serde_json::to_string(index)?)
+}
+
+fn now_ns() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+fn stat(path: &Path) -> Result<(u64, u64)> {
+    let meta = fs::metadata(path)?;
+    let mtime = meta
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+
+    Ok((mtime, meta.len()))
+}
+
+fn is_scalar(value: &Value) -> bool {
+    matches!(
+        value,
+        Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Null
+    )
+}
@@ -1,13 +1,15 @@
 use anyhow::Result;
-use std::{fs, collections::HashMap};
+use std::collections::HashMap;
 use serde_yaml::Value;
 use uuid::Uuid;
 
-use crate::memfs;
+use crate::{git, memfs};
+use crate::utils::fsio::write_atomic;
 use crate::utils::time::now_iso;
 
 pub fn run(name: &str) -> Result<()> {
     let root = memfs::resolve_workspace_root()?;
+    let _lock = memfs::lock_brane(&root)?;
     let path = memfs::projects_dir(&root).join(format!("{name}.yaml"));
 
     if path.exists() {
@@ -25,6 +27,7 @@ pub fn run(name: &str) -> Result<()> {
     project.insert("_created".to_string(), Value::String(now.clone()));
     project.insert("_updated".to_string(), Value::String(now));
 
-    fs::write(path, serde_yaml::to_string(&project)?)?;
+    write_atomic(&path, serde_yaml::to_string(&project)?)?;
+    git::autocommit(&root, &[path], &format!("add {name}"));
     Ok(())
 }
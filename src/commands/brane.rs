@@ -1,68 +1,106 @@
 use anyhow::Result;
 use colored::Colorize;
-use walkdir::WalkDir;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
-use crate::global;
-
-pub fn run() -> Result<()> {
-    // 1. Scan filesystem and register any membranes found
-    let home = dirs::home_dir().expect("Home directory not found");
-
-    for entry in WalkDir::new(&home)
-        .max_depth(6)
-        .follow_links(false)
-        .into_iter()
-        .filter_map(|e| e.ok())
-    {
-        if entry.file_name() == "id" {
-            let path = entry.path();
-
-            // only `.membrane/id`
-            if path.parent().map(|p| p.ends_with(".membrane")).unwrap_or(false) {
-                let membrane_dir = path.parent().unwrap();
-                let root = membrane_dir.parent().unwrap();
-
-                if let Ok(id) = fs::read_to_string(path) {
-                    let id = id.trim();
-
-                    // HARD GUARD: never register empty IDs
-                    if !id.is_empty() {
-                        global::register_workspace(id, root)?;
-                    }
-                }
-            }
+use crate::{config, discovery, global, memfs};
+use crate::utils::table::render_table;
+
+pub fn run(scan: bool, roots: Vec<PathBuf>, depth: Option<usize>) -> Result<()> {
+    // 1. Optionally scan the filesystem and register any membranes found
+    if scan {
+        let mut cfg = config::load_global_config()?.discovery;
+        if !roots.is_empty() {
+            cfg.roots = roots;
+        }
+        if let Some(d) = depth {
+            cfg.max_depth = d;
         }
+
+        let start = Instant::now();
+        let found = discovery::discover_branes(&cfg)?;
+
+        global::update_global_index(|index| {
+            for (id, root) in &found {
+                index.upsert(id, root);
+            }
+            Ok(())
+        })?;
+
+        println!(
+            "{}",
+            format!(
+                "Scanned in {:.1}s, found {} brane(s).",
+                start.elapsed().as_secs_f32(),
+                found.len()
+            )
+            .dimmed()
+        );
     }
 
     // 2. Reload global index
-    let index = global::load_global_index();
-
-    println!("{}", "=== Branes ===".bold().truecolor(255, 105, 180));
+    let index = global::load_global_index()?;
 
     // 3. Display safely
+    let mut rows = Vec::new();
+    let mut active_idx = None;
+
     for w in &index.workspaces {
         // GUARD AGAINST CORRUPT ENTRIES
         if w.id.trim().is_empty() {
             continue;
         }
 
-        let marker = if index.active.as_deref() == Some(&w.id) {
-            "*"
-        } else {
-            " "
-        };
+        let active = index.active.as_deref() == Some(&w.id);
+        if active {
+            active_idx = Some(rows.len());
+        }
 
         // SAFE SHORT ID (NO PANIC POSSIBLE)
         let short_id = w.id.chars().take(8).collect::<String>();
 
-        println!(
-            "{} {:<10} {}",
-            marker,
+        rows.push(vec![
+            if active { "*".into() } else { " ".into() },
             short_id,
-            w.root.display().to_string().dimmed()
-        );
+            w.name.clone().unwrap_or_else(|| "—".into()),
+            count_projects(&w.root),
+            format_last_seen(&w.last_seen),
+            w.root.display().to_string(),
+        ]);
+    }
+
+    render_table(
+        "Branes",
+        &["", "ID", "Name", "Projects", "Last seen", "Path"],
+        rows,
+        active_idx,
+    );
+
+    if !scan && index.workspaces.is_empty() {
+        println!("No branes registered yet. Run `me brane --scan` to search for them.");
     }
 
     Ok(())
 }
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+fn count_projects(root: &Path) -> String {
+    match fs::read_dir(memfs::projects_dir(root)) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("yaml"))
+            .count()
+            .to_string(),
+        Err(_) => "—".into(),
+    }
+}
+
+fn format_last_seen(stamp: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(stamp)
+        .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|_| stamp.to_string())
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use crate::global;
+
+/// Give a brane a human alias usable with `me checkout`
+pub fn run(target: &str, alias: &str) -> Result<()> {
+    let id = global::update_global_index(|index| {
+        let id = index.find(target)?.id.clone();
+        index.set_name(&id, alias)?;
+        Ok(id)
+    })?;
+
+    let short = id.chars().take(8).collect::<String>();
+    println!("✔ brane {} is now `{}`", short, alias);
+    Ok(())
+}
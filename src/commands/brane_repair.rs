@@ -0,0 +1,67 @@
+use anyhow::Result;
+use std::fs;
+
+use crate::global::{self, GlobalIndex};
+use crate::{config, discovery};
+use crate::utils::fsio::lock_exclusive;
+
+/// Rebuild `global.yaml` from the newest readable backup,
+/// or from a fresh scan for `.membrane/id` files.
+/// With `--scan` on a healthy index, scan results are merged into it.
+pub fn run(scan: bool) -> Result<()> {
+    let _lock = lock_exclusive(&global::global_lock_path()?)?;
+
+    let path = global::global_index_path()?;
+
+    if !scan && path.exists() && global::load_global_index().is_ok() {
+        println!("Global index {} is healthy. Nothing to repair.", path.display());
+        println!("Use `me brane repair --scan` to rebuild it from disk anyway.");
+        return Ok(());
+    }
+
+    // a healthy index keeps its names, aliases and active brane; the scan only adds to it
+    let (mut index, source) = match global::load_global_index() {
+        Ok(index) if path.exists() => (index, "existing index".to_string()),
+        _ => match (scan, restore_from_backup()) {
+            (false, Some((index, n))) => (index, format!("backup #{n}")),
+            _ => (GlobalIndex::default(), "fresh scan".to_string()),
+        },
+    };
+
+    // A scan always runs: it picks up branes created since the backup
+    let found = discovery::discover_branes(&config::load_global_config()?.discovery)?;
+    let before = index.workspaces.len();
+
+    for (id, root) in &found {
+        index.upsert(id, root);
+    }
+    let added = index.workspaces.len() - before;
+
+    // drop entries whose roots no longer exist
+    index.workspaces.retain(|w| w.root.exists());
+    if let Some(active) = &index.active {
+        if !index.workspaces.iter().any(|w| &w.id == active) {
+            index.active = None;
+        }
+    }
+
+    if let Some(aside) = global::replace_global_index(&index)? {
+        println!("Moved corrupt index to {}", aside.display());
+    }
+
+    println!(
+        "✔ repaired global index from {} ({} brane(s), {} new from scan)",
+        source,
+        index.workspaces.len(),
+        added,
+    );
+
+    Ok(())
+}
+
+fn restore_from_backup() -> Option<(GlobalIndex, usize)> {
+    (1..=global::BACKUP_COUNT).find_map(|n| {
+        let content = fs::read_to_string(global::backup_path(n).ok()?).ok()?;
+        global::parse_global_index(&content).ok().map(|i| (i, n))
+    })
+}
@@ -0,0 +1,185 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_yaml::Value;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::core::Project;
+use crate::{git, memfs};
+use crate::commands::show::{load_projects, render_inline_value};
+use crate::utils::filter::{matches_all, parse_filter, Filter};
+use crate::utils::project_writer::{materialize_project, write_project};
+
+/// Ask before touching more projects than this (skip with `--yes`)
+pub const CONFIRM_THRESHOLD: usize = 10;
+
+pub enum BulkOp {
+    Set { key: String, value: Value },
+    Unset { key: String },
+}
+
+impl BulkOp {
+    /// Whether applying the operation would modify `project`
+    fn changes(&self, project: &Project) -> bool {
+        match self {
+            BulkOp::Set { key, value } => project.get(key) != Some(value),
+            BulkOp::Unset { key } => project.contains_key(key),
+        }
+    }
+}
+
+/// Which projects a bulk command applies to
+pub struct Selection {
+    /// `--where` expressions, all of which must match
+    pub filters: Vec<String>,
+    /// `--all`
+    pub all: bool,
+}
+
+pub struct BulkOptions {
+    pub dry_run: bool,
+    pub yes: bool,
+}
+
+/// Apply `op` to every selected project through the canonical
+/// materialize/write path, then autocommit once. The preview and prompt run
+/// unlocked; targets are re-read and re-checked under the brane lock.
+pub fn run(op: BulkOp, selection: Selection, opts: BulkOptions) -> Result<()> {
+    let key = match &op {
+        BulkOp::Set { key, .. } | BulkOp::Unset { key } => key.clone(),
+    };
+    if key.starts_with('_') {
+        anyhow::bail!("Keys starting with '_' are reserved metadata keys.");
+    }
+
+    // exactly one of --all / --where
+    if selection.all != selection.filters.is_empty() {
+        anyhow::bail!("Use either --where or --all to choose projects");
+    }
+
+    let filters: Vec<Filter> = selection
+        .filters
+        .iter()
+        .map(|f| parse_filter(f))
+        .collect::<Result<_>>()?;
+
+    let root = memfs::resolve_workspace_root()?;
+    let dir = memfs::projects_dir(&root);
+
+    let matched: Vec<(String, Project)> = load_projects(&dir)?
+        .into_iter()
+        .filter(|(_, p)| matches_all(&filters, p))
+        .collect();
+    let total = matched.len();
+
+    // only projects the operation would actually change
+    let mut targets: Vec<(String, Project)> = matched
+        .into_iter()
+        .filter(|(_, p)| op.changes(p))
+        .collect();
+    targets.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let verb = match &op {
+        BulkOp::Set { key, value } => format!("set `{}` = {}", key, inline(value)),
+        BulkOp::Unset { key } => format!("remove `{}`", key),
+    };
+
+    if targets.is_empty() {
+        println!("Nothing to do: {} project(s) matched, none would change.", total);
+        return Ok(());
+    }
+
+    println!(
+        "{} on {} project(s) ({} matched):",
+        verb,
+        targets.len(),
+        total
+    );
+    for (name, project) in &targets {
+        let before = project
+            .get(&key)
+            .map(inline)
+            .unwrap_or_else(|| "—".into());
+        println!("  • {:<20} {}", name, format!("{}: {}", key, before).dimmed());
+    }
+
+    if opts.dry_run {
+        println!("{}", "Dry run: nothing was written.".dimmed());
+        return Ok(());
+    }
+
+    if targets.len() > CONFIRM_THRESHOLD && !opts.yes && !confirm(targets.len())? {
+        println!("Aborted. No projects were changed.");
+        return Ok(());
+    }
+
+    // other `me` writes may have run while we waited at the prompt
+    let _lock = memfs::lock_brane(&root)?;
+    let mut current: HashMap<String, Project> = load_projects(&dir)?.into_iter().collect();
+
+    let mut paths: Vec<PathBuf> = Vec::new();
+    let mut skipped = 0;
+
+    for (name, _) in targets {
+        let Some(mut project) = current.remove(&name) else {
+            skipped += 1;
+            continue;
+        };
+        if !matches_all(&filters, &project) || !op.changes(&project) {
+            skipped += 1;
+            continue;
+        }
+
+        match &op {
+            BulkOp::Set { key, value } => {
+                project.insert(key.clone(), value.clone());
+            }
+            BulkOp::Unset { key } => {
+                project.shift_remove(key);
+            }
+        }
+
+        let path = dir.join(format!("{name}.yaml"));
+        write_project(&path, materialize_project(project, &name)?)?;
+        paths.push(path);
+    }
+
+    if skipped > 0 {
+        println!(
+            "{}",
+            format!("{} project(s) changed in the meantime and were skipped.", skipped).dimmed()
+        );
+    }
+    if paths.is_empty() {
+        println!("No projects were changed.");
+        return Ok(());
+    }
+
+    let message = match &op {
+        BulkOp::Set { key, .. } => format!("set {key} on {} projects", paths.len()),
+        BulkOp::Unset { key } => format!("unset {key} on {} projects", paths.len()),
+    };
+    git::autocommit(&root, &paths, &message);
+
+    println!("✔ updated {} project(s)", paths.len());
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+fn confirm(count: usize) -> Result<bool> {
+    print!("⚠️  This will modify {count} projects. Continue? [y/N] ");
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+fn inline(value: &Value) -> String {
+    render_inline_value(value).unwrap_or_else(|| "[…]".into())
+}
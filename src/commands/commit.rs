@@ -0,0 +1,16 @@
+use anyhow::Result;
+
+use crate::{git, memfs};
+
+/// Flush changes collected by a batched `git.autocommit`
+pub fn run() -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let _lock = memfs::lock_brane(&root)?;
+
+    match git::flush(&root)? {
+        0 => println!("Nothing to commit."),
+        n => println!("✔ committed {} batched change(s)", n),
+    }
+
+    Ok(())
+}
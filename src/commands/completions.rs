@@ -0,0 +1,17 @@
+use anyhow::Result;
+use clap_complete::env::Shells;
+use std::io;
+
+/// Print the shell registration script for dynamic completions.
+/// The script calls back into `me` (with `COMPLETE=<shell>`) on every <Tab>.
+pub fn run(shell: &str) -> Result<()> {
+    let shells = Shells::builtins();
+
+    let completer = shells.completer(shell).ok_or_else(|| {
+        let names = shells.names().collect::<Vec<_>>().join(", ");
+        anyhow::anyhow!("Unsupported shell '{}'. Use one of: {}", shell, names)
+    })?;
+
+    completer.write_registration("COMPLETE", "me", "me", "me", &mut io::stdout())?;
+    Ok(())
+}
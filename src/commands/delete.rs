@@ -1,10 +1,14 @@
 use anyhow::Result;
 use std::{fs, io::{self, Write}};
 
-use crate::memfs;
+use crate::{git, memfs};
+use crate::utils::resolve::check_project_name;
 
 pub fn run(project: &str) -> Result<()> {
+    check_project_name(project)?;
+
     let root = memfs::resolve_workspace_root()?;
+    let _lock = memfs::lock_brane(&root)?;
     let path = memfs::projects_dir(&root).join(format!("{project}.yaml"));
 
     if !path.exists() {
@@ -33,6 +37,7 @@ pub fn run(project: &str) -> Result<()> {
 
     // Delete
     fs::remove_file(&path)?;
+    git::autocommit(&root, &[path], &format!("rm {project}"));
     println!("Project `{project}` deleted.");
 
     Ok(())
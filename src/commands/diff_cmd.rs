@@ -0,0 +1,145 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::json;
+use serde_yaml::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::Project;
+use crate::diff::{diff_projects, Change};
+use crate::{git, memfs};
+use crate::commands::show::render_inline_value;
+use crate::utils::resolve::{check_project_name, resolve_project};
+
+/// Compare two projects. Each side may be:
+/// `name` / ID prefix, `<brane>:<project>`, `<project>@<git rev>`, or a path to a YAML file.
+pub fn run(a: &str, b: &str, format: &str, order: bool) -> Result<()> {
+    let (label_a, project_a) = load_side(a)?;
+    let (label_b, project_b) = load_side(b)?;
+
+    let changes = diff_projects(&project_a, &project_b, order);
+
+    match format {
+        "json" => {
+            let out = json!({
+                "a": label_a,
+                "b": label_b,
+                "identical": changes.is_empty(),
+                "changes": changes,
+            });
+            println!("{}", serde_json::to_string_pretty(&out)?);
+        }
+        "text" => render_text(&label_a, &label_b, &changes),
+        other => anyhow::bail!("Unknown format `{}` (expected text or json)", other),
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Loading
+// ------------------------------------------------------------
+
+fn load_side(spec: &str) -> Result<(String, Project)> {
+    // 1. a YAML file anywhere on disk
+    let file = Path::new(spec);
+    if file.is_file() {
+        let content = fs::read_to_string(file)?;
+        let project = serde_yaml::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("{} is not a YAML mapping: {}", spec, e))?;
+        return Ok((spec.to_string(), project));
+    }
+
+    // 2. <project>@<rev> (the rev may itself contain `@`, e.g. `HEAD@{1.week.ago}`)
+    let (target, rev) = match spec.split_once('@') {
+        Some((t, r)) if !r.is_empty() => (t, Some(r)),
+        _ => (spec, None),
+    };
+
+    // 3. <brane>:<project>
+    let (root, input, brane) = match target.split_once(':') {
+        Some((brane, project)) if !brane.is_empty() => {
+            (memfs::resolve_brane_target(brane)?, project, Some(brane))
+        }
+        _ => (memfs::resolve_workspace_root()?, target, None),
+    };
+
+    let dir = memfs::projects_dir(&root);
+    let label = |name: &str| match brane {
+        Some(b) => format!("{b}:{name}"),
+        None => name.to_string(),
+    };
+
+    match rev {
+        None => {
+            let (name, project) = resolve_project(&dir, input)?;
+            Ok((label(&name), project))
+        }
+        Some(rev) => {
+            // the project may be gone from the working tree; fall back to the literal name
+            let name = match resolve_project(&dir, input) {
+                Ok((n, _)) => n,
+                Err(_) => {
+                    check_project_name(input)?;
+                    input.to_string()
+                }
+            };
+
+            let path: PathBuf = [".membrane", "projects", &format!("{name}.yaml")].iter().collect();
+            let content = git::show_at(&root, rev, &path)?;
+            let project = serde_yaml::from_str(&content)
+                .map_err(|e| anyhow::anyhow!("{}@{} is not a YAML mapping: {}", name, rev, e))?;
+
+            Ok((format!("{}@{}", label(&name), rev), project))
+        }
+    }
+}
+
+// ------------------------------------------------------------
+// Rendering
+// ------------------------------------------------------------
+
+fn render_text(label_a: &str, label_b: &str, changes: &[Change]) {
+    println!("{}", format!("--- {}", label_a).red());
+    println!("{}", format!("+++ {}", label_b).green());
+
+    if changes.is_empty() {
+        println!("{}", "No differences.".dimmed());
+        return;
+    }
+
+    for change in changes {
+        match change {
+            Change::Added { path, new } => {
+                println!("{}", format!("+ {}: {}", path, inline(new)).green());
+            }
+            Change::Removed { path, old } => {
+                println!("{}", format!("- {}: {}", path, inline(old)).red());
+            }
+            Change::Changed { path, old, new } => {
+                println!(
+                    "{} {}: {} → {}",
+                    "~".yellow(),
+                    path.yellow(),
+                    inline(old).red(),
+                    inline(new).green()
+                );
+            }
+            Change::Reordered { path, old, new } => {
+                println!(
+                    "{} {}: key order [{}] → [{}]",
+                    "↕".cyan(),
+                    path.cyan(),
+                    old.join(", "),
+                    new.join(", ")
+                );
+            }
+        }
+    }
+}
+
+/// Scalars as in `me show`, anything else as compact JSON
+fn inline(value: &Value) -> String {
+    render_inline_value(value)
+        .unwrap_or_else(|| serde_json::to_string(value).unwrap_or_default())
+}
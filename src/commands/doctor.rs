@@ -0,0 +1,324 @@
+use anyhow::Result;
+use colored::Colorize;
+use indexmap::IndexMap;
+use serde_yaml::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+use crate::core::Project;
+use crate::global;
+use crate::memfs;
+use crate::sweep;
+use crate::utils::fsio::write_atomic;
+use crate::utils::project_writer::{materialize_project, write_project, RESERVED_KEYS};
+
+/// A problem found in the brane, with an optional safe repair
+struct Issue {
+    target: String,
+    problem: String,
+    fix: Option<Fix>,
+}
+
+enum Fix {
+    /// Rewrite a project through the materializer
+    Rewrite { path: PathBuf, name: String, data: Project },
+    /// Remove a leftover temp file from an interrupted write
+    RemoveTemp(PathBuf),
+    /// Write a fresh `.membrane/id`
+    NewBraneId,
+    /// Register (or re-point) this brane in the global index
+    Register(String),
+}
+
+pub fn run(fix: bool) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let _lock = memfs::lock_brane(&root)?;
+
+    println!("{} {}", "Checking".dimmed(), root.display());
+    println!();
+
+    let mut issues = check_brane_id(&root)?;
+    issues.extend(check_projects(&memfs::projects_dir(&root))?);
+
+    if issues.is_empty() {
+        println!("✔ No problems found.");
+        return Ok(());
+    }
+
+    let mut fixed = 0;
+
+    for issue in issues.iter_mut() {
+        let marker = if issue.fix.is_some() { "!".yellow() } else { "✗".red() };
+        println!("{} {:<24} {}", marker, issue.target, issue.problem);
+
+        if fix {
+            if let Some(f) = issue.fix.take() {
+                apply(&root, f)?;
+                println!("  {} fixed", "✔".green());
+                fixed += 1;
+            }
+        }
+    }
+
+    let fixable = issues.iter().filter(|i| i.fix.is_some()).count();
+
+    println!();
+    if fix {
+        println!(
+            "Found {} problem(s), fixed {}. {} need manual attention.",
+            issues.len(),
+            fixed,
+            issues.len() - fixed
+        );
+    } else {
+        println!(
+            "Found {} problem(s); {} can be fixed with `me doctor --fix`.",
+            issues.len(),
+            fixable
+        );
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// BRANE IDENTITY
+// ------------------------------------------------------------
+
+fn check_brane_id(root: &Path) -> Result<Vec<Issue>> {
+    let mut issues = Vec::new();
+
+    let id = match memfs::read_brane_id(root) {
+        Ok(id) => id,
+        Err(_) => {
+            issues.push(Issue {
+                target: ".membrane/id".into(),
+                problem: "missing or empty brane ID".into(),
+                fix: Some(Fix::NewBraneId),
+            });
+            return Ok(issues);
+        }
+    };
+
+    if Uuid::parse_str(&id).is_err() {
+        issues.push(Issue {
+            target: ".membrane/id".into(),
+            problem: format!("brane ID `{id}` is not a UUID"),
+            fix: None,
+        });
+    }
+
+    let index = global::load_global_index()?;
+
+    match index.workspaces.iter().find(|w| w.id == id) {
+        None => issues.push(Issue {
+            target: "global index".into(),
+            problem: "brane is not registered".into(),
+            fix: Some(Fix::Register(id)),
+        }),
+        Some(w) if !same_path(&w.root, root) => issues.push(Issue {
+            target: "global index".into(),
+            problem: format!("registered at {} instead", w.root.display()),
+            fix: Some(Fix::Register(id)),
+        }),
+        Some(_) => {}
+    }
+
+    Ok(issues)
+}
+
+// ------------------------------------------------------------
+// PROJECT FILES
+// ------------------------------------------------------------
+
+fn check_projects(dir: &Path) -> Result<Vec<Issue>> {
+    let mut issues = Vec::new();
+    let mut ids: IndexMap<String, Vec<(String, Option<String>)>> = IndexMap::new();
+    let mut parsed: IndexMap<String, (PathBuf, Project, Vec<String>)> = IndexMap::new();
+
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        let file = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+
+        if path.is_dir() {
+            issues.push(Issue {
+                target: file,
+                problem: "unexpected directory".into(),
+                fix: None,
+            });
+            continue;
+        }
+
+        if path.extension().and_then(|s| s.to_str()) != Some("yaml") {
+            let temp = file.starts_with('.') && file.ends_with(".tmp");
+            issues.push(Issue {
+                target: file,
+                problem: if temp {
+                    "leftover temp file from an interrupted write".into()
+                } else {
+                    "not a .yaml file (ignored by Membrane)".into()
+                },
+                fix: temp.then(|| Fix::RemoveTemp(path.clone())),
+            });
+            continue;
+        }
+
+        let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        let content = fs::read_to_string(&path)?;
+
+        let project: Project = match serde_yaml::from_str::<Value>(&content) {
+            Ok(Value::Mapping(_)) => match serde_yaml::from_str(&content) {
+                Ok(p) => p,
+                Err(e) => {
+                    issues.push(Issue {
+                        target: file,
+                        problem: format!("mapping has non-string keys ({e})"),
+                        fix: None,
+                    });
+                    continue;
+                }
+            },
+            Ok(other) => {
+                issues.push(Issue {
+                    target: file,
+                    problem: format!("not a YAML mapping (found {})", sweep::type_name(&other)),
+                    fix: None,
+                });
+                continue;
+            }
+            Err(e) => {
+                issues.push(Issue {
+                    target: file,
+                    problem: format!("invalid YAML: {e}"),
+                    fix: None,
+                });
+                continue;
+            }
+        };
+
+        let mut problems = Vec::new();
+
+        match project.get("_id").and_then(|v| v.as_str()) {
+            Some(id) => {
+                let created = project
+                    .get("_created")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string);
+                ids.entry(id.to_string()).or_default().push((name.clone(), created));
+            }
+            None => problems.push("missing `_id`".to_string()),
+        }
+
+        for key in ["_created", "_updated"] {
+            if !project.contains_key(key) {
+                problems.push(format!("missing `{key}`"));
+            }
+        }
+
+        match project.get("name").and_then(|v| v.as_str()) {
+            Some(n) if n == name => {}
+            Some(n) => problems.push(format!("`name` is `{n}` but the file is `{name}.yaml`")),
+            None => problems.push("missing `name`".to_string()),
+        }
+
+        let rogue: Vec<&String> = project
+            .keys()
+            .filter(|k| k.starts_with('_') && !RESERVED_KEYS.contains(&k.as_str()))
+            .collect();
+        if !rogue.is_empty() {
+            let listed = rogue.iter().map(|k| format!("`{k}`")).collect::<Vec<_>>().join(", ");
+            problems.push(format!("rogue reserved key(s) {listed}"));
+        }
+
+        parsed.insert(name, (path, project, problems));
+    }
+
+    // duplicate IDs: the oldest project keeps its ID, the others get a new one
+    for (_, mut owners) in ids.into_iter().filter(|(_, o)| o.len() > 1) {
+        owners.sort_by(|a, b| a.1.cmp(&b.1));
+        let keeper = owners[0].0.clone();
+
+        for (name, _) in owners.into_iter().skip(1) {
+            if let Some((_, project, problems)) = parsed.get_mut(&name) {
+                project.shift_remove("_id");
+                problems.push(format!("duplicate `_id` (shared with `{keeper}`)"));
+            }
+        }
+    }
+
+    for (name, (path, project, problems)) in parsed {
+        if problems.is_empty() {
+            continue;
+        }
+
+        issues.push(Issue {
+            target: format!("{name}.yaml"),
+            problem: problems.join("; "),
+            fix: Some(Fix::Rewrite {
+                path,
+                name,
+                data: rescue_rogue_keys(project),
+            }),
+        });
+    }
+
+    Ok(issues)
+}
+
+/// Rogue `_keys` would be stripped by the materializer.
+/// Keep their values by dropping the underscore when that name is free.
+fn rescue_rogue_keys(project: Project) -> Project {
+    let mut out: Project = IndexMap::new();
+
+    for (k, v) in &project {
+        let is_rogue = k.starts_with('_') && !RESERVED_KEYS.contains(&k.as_str());
+
+        if is_rogue {
+            let plain = k.trim_start_matches('_');
+            if !plain.is_empty() && !project.contains_key(plain) && !out.contains_key(plain) {
+                out.insert(plain.to_string(), v.clone());
+            }
+            continue;
+        }
+
+        out.insert(k.clone(), v.clone());
+    }
+
+    out
+}
+
+// ------------------------------------------------------------
+// REPAIRS
+// ------------------------------------------------------------
+
+fn apply(root: &Path, fix: Fix) -> Result<()> {
+    match fix {
+        Fix::Rewrite { path, name, data } => {
+            let ordered = materialize_project(data, &name)?;
+            write_project(&path, ordered)
+        }
+        Fix::RemoveTemp(path) => Ok(fs::remove_file(path)?),
+        Fix::NewBraneId => {
+            let id = Uuid::new_v4().to_string();
+            write_atomic(&root.join(".membrane").join("id"), &id)?;
+            global::register_workspace(&id, root)
+        }
+        Fix::Register(id) => global::register_workspace(&id, root),
+    }
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
@@ -0,0 +1,140 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::core::Project;
+use crate::{git, memfs};
+use crate::utils::fsio::write_atomic;
+use crate::utils::project_writer::{materialize_project, write_project};
+use crate::utils::resolve::resolve_project;
+
+/// Marker for the error banner prepended when the editor is re-opened
+const ERROR_PREFIX: &str = "# me edit:";
+
+/// Keys that identify a project and must survive an edit unchanged
+const PINNED_KEYS: &[&str] = &["_id", "_created"];
+
+pub fn run(input: &str) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let dir = memfs::projects_dir(&root);
+
+    let (name, original) = resolve_project(&dir, input)?;
+    let path = dir.join(format!("{name}.yaml"));
+    let on_disk = fs::read_to_string(&path)?;
+
+    let tmp = std::env::temp_dir().join(format!("me-edit-{}-{}.yaml", name, std::process::id()));
+    write_atomic(&tmp, &on_disk)?;
+
+    let edited = loop {
+        launch_editor(&tmp)?;
+
+        let content = fs::read_to_string(&tmp)?;
+        let body = strip_error_banner(&content);
+
+        if body.trim().is_empty() {
+            let _ = fs::remove_file(&tmp);
+            println!("Aborted. `{name}` was not changed.");
+            return Ok(());
+        }
+
+        if body == on_disk {
+            let _ = fs::remove_file(&tmp);
+            println!("No changes.");
+            return Ok(());
+        }
+
+        match validate(&body, &original) {
+            Ok(project) => break project,
+            Err(e) => {
+                let banner = format!(
+                    "{ERROR_PREFIX} {}\n{ERROR_PREFIX} Fix the YAML below, or empty the file to abort.\n",
+                    e.to_string().replace('\n', " ")
+                );
+                write_atomic(&tmp, format!("{banner}{body}"))?;
+            }
+        }
+    };
+
+    if edited.get("name").and_then(|v| v.as_str()) != Some(name.as_str()) {
+        println!("Note: `name` follows the filename and was kept as `{name}`.");
+    }
+
+    let _lock = memfs::lock_brane(&root)?;
+
+    // someone else wrote the project while the editor was open
+    if fs::read_to_string(&path)? != on_disk {
+        anyhow::bail!(
+            "`{}` changed on disk while you were editing. Your version is kept at {}",
+            name,
+            tmp.display()
+        );
+    }
+
+    let ordered = materialize_project(edited, &name)?;
+    write_project(&path, ordered)?;
+    git::autocommit(&root, &[path], &format!("edit {name}"));
+
+    let _ = fs::remove_file(&tmp);
+    println!("✔ saved `{name}`");
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+/// `$VISUAL`, then `$EDITOR`, then a platform default.
+/// The variable may carry arguments, e.g. `code --wait`.
+fn launch_editor(file: &Path) -> Result<()> {
+    let editor = ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|v| std::env::var(v).ok())
+        .find(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| if cfg!(windows) { "notepad".into() } else { "vi".into() });
+
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(file)
+        .status()
+        .with_context(|| format!("Failed to launch editor `{}`", editor))?;
+
+    if !status.success() {
+        anyhow::bail!("Editor `{}` exited with {}", editor, status);
+    }
+
+    Ok(())
+}
+
+fn strip_error_banner(content: &str) -> String {
+    content
+        .split_inclusive('\n')
+        .skip_while(|line| line.starts_with(ERROR_PREFIX))
+        .collect()
+}
+
+fn validate(body: &str, original: &Project) -> Result<Project> {
+    let edited: Project = serde_yaml::from_str(body)
+        .map_err(|e| anyhow::anyhow!("invalid YAML mapping: {e}"))?;
+
+    for key in PINNED_KEYS {
+        if edited.get(*key) != original.get(*key) {
+            anyhow::bail!("reserved key `{key}` cannot be changed or removed");
+        }
+    }
+
+    let rogue: Vec<&String> = edited
+        .keys()
+        .filter(|k| k.starts_with('_') && !original.contains_key(*k) && k.as_str() != "_updated")
+        .collect();
+
+    if !rogue.is_empty() {
+        anyhow::bail!("keys starting with '_' are reserved: {:?}", rogue);
+    }
+
+    Ok(edited)
+}
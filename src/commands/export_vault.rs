@@ -0,0 +1,275 @@
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde_yaml::{Mapping, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::Project;
+use crate::{config, memfs};
+use crate::commands::show::load_projects;
+use crate::utils::fsio::write_atomic;
+use crate::utils::project_writer::RESERVED_KEYS;
+
+/// Front-matter key that ties a note back to its project and brane across renames.
+/// The `_` prefix keeps it clear of user keys (which can't start with `_`).
+const MARKER_KEY: &str = "_membrane";
+
+/// Index note written next to the project notes
+const INDEX_NOTE: &str = "_index.md";
+
+/// Strings longer than this (or spanning lines) become body sections
+const INLINE_LIMIT: usize = 80;
+
+/// Write one markdown note per project into `dir`, plus an index note.
+/// Notes are keyed by project ID, so re-running updates them in place.
+/// Notes exported from other branes into the same vault are left alone.
+pub fn run(dir: &str) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let out = Path::new(dir);
+    fs::create_dir_all(out)?;
+
+    let brane_id = memfs::read_brane_id(&root)?;
+    let references = config::load_config(&root)?.references;
+
+    let projects = load_projects(&memfs::projects_dir(&root))?;
+    let links = Links {
+        targets: link_targets(&projects),
+        references,
+    };
+    let existing = existing_notes(out, &brane_id)?;
+
+    let mut written = 0;
+    let mut unchanged = 0;
+    let mut removed = 0;
+
+    for (name, project) in &projects {
+        let path = out.join(format!("{name}.md"));
+        let id = project_id(project);
+
+        // project was renamed since the last export
+        if let Some(old) = existing.get(id).filter(|old| **old != path) {
+            fs::remove_file(old)?;
+        }
+
+        if write_if_changed(&path, &render_note(name, project, &brane_id, &links)?)? {
+            written += 1;
+        } else {
+            unchanged += 1;
+        }
+    }
+
+    // notes of this brane whose project is gone
+    for (id, path) in &existing {
+        if !projects.iter().any(|(_, p)| project_id(p) == id) && path.exists() {
+            fs::remove_file(path)?;
+            removed += 1;
+        }
+    }
+
+    write_if_changed(&out.join(INDEX_NOTE), &render_index(&root, &brane_id, &projects))?;
+
+    println!(
+        "✔ exported {} project(s) to {} ({} written, {} unchanged, {} removed)",
+        projects.len(),
+        out.display(),
+        written,
+        unchanged,
+        removed
+    );
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Rendering
+// ------------------------------------------------------------
+
+fn render_note(name: &str, project: &Project, brane_id: &str, links: &Links) -> Result<String> {
+    let mut front = Mapping::new();
+    let mut sections = Vec::new();
+
+    let mut marker = Mapping::new();
+    marker.insert("id".into(), Value::String(project_id(project).to_string()));
+    marker.insert("brane".into(), Value::String(brane_id.to_string()));
+    front.insert(MARKER_KEY.into(), Value::Mapping(marker));
+
+    for (key, value) in project {
+        if RESERVED_KEYS.contains(&key.as_str()) || key == "name" || key == MARKER_KEY {
+            continue;
+        }
+
+        let linked = is_reference_key(key, &links.references);
+
+        match value {
+            Value::String(s) if is_long(s) => {
+                let text = if linked { link_text(s, &links.targets) } else { s.clone() };
+                sections.push((key, text));
+            }
+            _ if linked => {
+                front.insert(key.clone().into(), link_value(value, &links.targets));
+            }
+            _ => {
+                front.insert(key.clone().into(), value.clone());
+            }
+        }
+    }
+
+    let mut md = String::new();
+    md.push_str("---\n");
+    md.push_str(&serde_yaml::to_string(&front)?);
+    md.push_str("---\n\n");
+
+    md.push_str(&format!("# {}\n", name));
+
+    for (key, text) in sections {
+        md.push_str(&format!("\n## {}\n\n{}\n", key, text.trim_end()));
+    }
+
+    Ok(md)
+}
+
+fn render_index(root: &Path, brane_id: &str, projects: &[(String, Project)]) -> String {
+    let short = brane_id.chars().take(8).collect::<String>();
+
+    let mut md = format!(
+        "# Projects\n\n> Brane: {} [{}]\n\n",
+        root.display(),
+        short
+    );
+
+    let mut names: Vec<&String> = projects.iter().map(|(n, _)| n).collect();
+    names.sort();
+
+    for name in names {
+        md.push_str(&format!("- [[{}]]\n", name));
+    }
+
+    md
+}
+
+// ------------------------------------------------------------
+// Links
+// ------------------------------------------------------------
+
+struct Links {
+    /// name or full `_id` → project name
+    targets: HashMap<String, String>,
+    /// `references:` from the brane config
+    references: Vec<String>,
+}
+
+/// Whether values of `key` link to the projects they name: every user key,
+/// or only the keys listed under `references:` in the brane config when set.
+/// Shared with `me site build`.
+pub fn is_reference_key(key: &str, references: &[String]) -> bool {
+    !key.starts_with('_') && key != "name" && (references.is_empty() || references.iter().any(|r| r == key))
+}
+
+/// Strings that point at a project (its name or full `_id`) → project name
+pub fn link_targets(projects: &[(String, Project)]) -> HashMap<String, String> {
+    let mut links = HashMap::new();
+
+    for (name, project) in projects {
+        links.insert(name.clone(), name.clone());
+        links.insert(project_id(project).to_string(), name.clone());
+    }
+
+    links.remove("");
+    links
+}
+
+fn link_value(value: &Value, links: &HashMap<String, String>) -> Value {
+    match value {
+        Value::String(s) => match links.get(s.trim()) {
+            Some(name) => Value::String(format!("[[{}]]", name)),
+            None => value.clone(),
+        },
+        Value::Sequence(items) => Value::Sequence(items.iter().map(|i| link_value(i, links)).collect()),
+        Value::Mapping(map) => Value::Mapping(
+            map.iter()
+                .map(|(k, v)| (k.clone(), link_value(v, links)))
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
+/// Full project IDs inside long text become wikilinks
+fn link_text(text: &str, links: &HashMap<String, String>) -> String {
+    let mut out = text.to_string();
+
+    for (id, name) in ids_in(text, links) {
+        out = out.replace(id, &format!("[[{}]]", name));
+    }
+
+    out
+}
+
+/// (full `_id`, project name) of every project whose ID appears inside `text`
+pub fn ids_in<'a>(text: &str, links: &'a HashMap<String, String>) -> Vec<(&'a str, &'a str)> {
+    links
+        .iter()
+        .filter(|(target, name)| target != name && text.contains(target.as_str()))
+        .map(|(target, name)| (target.as_str(), name.as_str()))
+        .collect()
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+pub fn project_id(project: &Project) -> &str {
+    project.get("_id").and_then(|v| v.as_str()).unwrap_or_default()
+}
+
+fn is_long(s: &str) -> bool {
+    s.contains('\n') || s.chars().count() > INLINE_LIMIT
+}
+
+/// This brane's notes from a previous export (project ID → path), by the
+/// marker in their front-matter. Files without the marker are the user's own;
+/// notes of other branes belong to them.
+fn existing_notes(dir: &Path, brane_id: &str) -> Result<IndexMap<String, PathBuf>> {
+    let mut notes = IndexMap::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|s| s.to_str()) != Some("md") {
+            continue;
+        }
+
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        let Some(marker) = content
+            .strip_prefix("---\n")
+            .and_then(|rest| rest.split_once("\n---"))
+            .and_then(|(yaml, _)| serde_yaml::from_str::<Project>(yaml).ok())
+            .and_then(|mut front| front.shift_remove(MARKER_KEY))
+        else {
+            continue;
+        };
+
+        let field = |k: &str| marker.get(k).and_then(|x| x.as_str()).map(str::to_string);
+
+        if field("brane").as_deref() == Some(brane_id) {
+            if let Some(id) = field("id") {
+                notes.insert(id, path);
+            }
+        }
+    }
+
+    Ok(notes)
+}
+
+/// Returns whether the file was (re)written
+fn write_if_changed(path: &Path, content: &str) -> Result<bool> {
+    if fs::read_to_string(path).is_ok_and(|old| old == content) {
+        return Ok(false);
+    }
+
+    write_atomic(path, content)?;
+    Ok(true)
+}
@@ -0,0 +1,132 @@
+use anyhow::Result;
+use serde_yaml::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::core::Project;
+use crate::memfs;
+use crate::commands::push::{create_project, resolve_project_name};
+
+/// Import markdown notes: front-matter becomes the project, the body goes under `body_key`
+pub fn run(target: &str, body_key: &str) -> Result<()> {
+    if body_key.starts_with('_') || body_key == "name" {
+        anyhow::bail!("`{}` cannot hold the note body (reserved key)", body_key);
+    }
+
+    let target = Path::new(target);
+    if !target.exists() {
+        anyhow::bail!("File not found: {}", target.display());
+    }
+
+    let root = memfs::resolve_workspace_root()?;
+    let files = collect_markdown(target);
+
+    if files.is_empty() {
+        anyhow::bail!("No markdown files found in {}", target.display());
+    }
+
+    let mut imported = 0;
+    let mut skipped = 0;
+
+    for file in &files {
+        match import_file(&root, file, body_key) {
+            Ok(name) => {
+                println!("✔ {} → project `{}`", file.display(), name);
+                imported += 1;
+            }
+            Err(e) => {
+                println!("⚠️  skipped {}: {}", file.display(), e);
+                skipped += 1;
+            }
+        }
+    }
+
+    println!("\nImported {} project(s), skipped {}.", imported, skipped);
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+fn collect_markdown(target: &Path) -> Vec<PathBuf> {
+    if target.is_file() {
+        return vec![target.to_path_buf()];
+    }
+
+    let mut files: Vec<PathBuf> = WalkDir::new(target)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !e.file_name().to_string_lossy().starts_with('.'))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| is_markdown(p))
+        .collect();
+
+    files.sort();
+    files
+}
+
+fn is_markdown(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|s| s.to_str()),
+        Some("md" | "markdown")
+    )
+}
+
+fn import_file(root: &Path, file: &Path, body_key: &str) -> Result<String> {
+    let content = fs::read_to_string(file)?;
+    let (mut data, body) = split_front_matter(&content)?;
+
+    if !body.is_empty() {
+        if data.contains_key(body_key) {
+            anyhow::bail!("front-matter already has a `{}` key (use --body-key)", body_key);
+        }
+        data.insert(body_key.to_string(), Value::String(body));
+    }
+
+    let name = resolve_project_name(None, &data, Some(file))?;
+    create_project(root, data, &name)?;
+
+    Ok(name)
+}
+
+/// Split `---` delimited YAML front-matter from the markdown body.
+/// A file without front-matter is all body.
+fn split_front_matter(content: &str) -> Result<(Project, String)> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return Ok((Project::new(), content.trim().to_string()));
+    };
+
+    let mut yaml_len = None;
+    let mut offset = 0;
+
+    for line in rest.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed == "---" || trimmed == "..." {
+            yaml_len = Some((offset, offset + line.len()));
+            break;
+        }
+        offset += line.len();
+    }
+
+    let Some((yaml_end, body_start)) = yaml_len else {
+        anyhow::bail!("front-matter is not closed with `---`");
+    };
+
+    let yaml = &rest[..yaml_end];
+    let data: Project = if yaml.trim().is_empty() {
+        Project::new()
+    } else {
+        serde_yaml::from_str(yaml)
+            .map_err(|e| anyhow::anyhow!("front-matter must be a YAML mapping: {e}"))?
+    };
+
+    Ok((data, rest[body_start..].trim().to_string()))
+}
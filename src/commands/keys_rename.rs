@@ -2,7 +2,8 @@ use anyhow::Result;
 use std::fs;
 
 use crate::core::Project;
-use crate::memfs;
+use crate::{git, memfs};
+use crate::utils::fsio::write_atomic;
 use crate::utils::time::now_iso;
 
 pub fn run(old: &str, new: &str, project_filter: Option<&str>) -> Result<()> {
@@ -12,6 +13,7 @@ pub fn run(old: &str, new: &str, project_filter: Option<&str>) -> Result<()> {
 
     let root = memfs::resolve_workspace_root()?;
     let projects_dir = memfs::projects_dir(&root);
+    let _lock = memfs::lock_brane(&root)?;
 
     println!("Renaming key `{}` → `{}`", old, new);
     if let Some(p) = project_filter {
@@ -19,7 +21,7 @@ pub fn run(old: &str, new: &str, project_filter: Option<&str>) -> Result<()> {
     }
     println!();
 
-    let mut updated = 0;
+    let mut touched = Vec::new();
 
     for entry in fs::read_dir(&projects_dir)? {
         let entry = entry?;
@@ -62,15 +64,16 @@ pub fn run(old: &str, new: &str, project_filter: Option<&str>) -> Result<()> {
             serde_yaml::Value::String(now_iso()),
         );
 
-        fs::write(&path, serde_yaml::to_string(&project)?)?;
+        write_atomic(&path, serde_yaml::to_string(&project)?)?;
         println!("✔ {}", name);
-        updated += 1;
+        touched.push(path);
     }
 
-    if updated == 0 {
+    if touched.is_empty() {
         println!("\nNo projects were updated.");
     } else {
-        println!("\nDone. Updated {} project(s).", updated);
+        println!("\nDone. Updated {} project(s).", touched.len());
+        git::autocommit(&root, &touched, &format!("rename key {old} to {new}"));
     }
 
     Ok(())
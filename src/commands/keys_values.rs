@@ -0,0 +1,241 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+
+use crate::memfs;
+use crate::commands::show::{load_projects, render_inline_value};
+use crate::utils::table::render_table;
+
+/// Project names listed per value before eliding the rest
+const LISTED_PROJECTS: usize = 5;
+
+/// Common status abbreviations that aren't initials of what they stand for,
+/// as normalized forms (`WIP` ↔ `in progress`)
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("wip", "inprogress"),
+    ("wip", "workinprogress"),
+    ("tbd", "tobedetermined"),
+    ("tbd", "tobedecided"),
+    ("na", "notapplicable"),
+    ("poc", "proofofconcept"),
+];
+
+/// Show which values `key` takes across the brane, or with `similar`,
+/// which of those values look like spellings of the same thing.
+pub fn run(key: &str, similar: bool) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let projects = load_projects(&memfs::projects_dir(&root))?;
+
+    // value → projects using it
+    let mut values: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut missing = 0;
+
+    for (name, project) in &projects {
+        let Some(value) = project.get(key) else {
+            missing += 1;
+            continue;
+        };
+
+        let mut rendered = Vec::new();
+        flatten(value, &mut rendered);
+        rendered.sort();
+        rendered.dedup();
+
+        for v in rendered {
+            values.entry(v).or_default().push(name.clone());
+        }
+    }
+
+    if values.is_empty() {
+        anyhow::bail!("No project has key `{}`", key);
+    }
+
+    for names in values.values_mut() {
+        names.sort();
+    }
+
+    if similar {
+        show_similar(key, &values);
+        return Ok(());
+    }
+
+    let mut rows: Vec<(String, Vec<String>)> = values.into_iter().collect();
+    rows.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+
+    let rows = rows
+        .into_iter()
+        .map(|(value, names)| vec![value, names.len().to_string(), project_list(&names)])
+        .collect();
+
+    render_table(
+        &format!("Values of `{}`", key),
+        &["Value", "Count", "Projects"],
+        rows,
+        None,
+    );
+
+    if missing > 0 {
+        println!("{}", format!("{} project(s) without `{}`", missing, key).dimmed());
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// --similar
+// ------------------------------------------------------------
+
+fn show_similar(key: &str, values: &BTreeMap<String, Vec<String>>) {
+    let distinct: Vec<&String> = values.keys().collect();
+    let groups = similar_groups(&distinct);
+
+    if groups.is_empty() {
+        println!("No near-duplicate values of `{}`.", key);
+        return;
+    }
+
+    println!("Possible duplicate values of `{}`:\n", key);
+
+    for group in groups {
+        let line = group
+            .iter()
+            .map(|v| format!("{} ({})", v, values[*v].len()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("{line}");
+    }
+
+    println!(
+        "\n{}",
+        format!("Settle on one spelling with `me values replace {key} <old> <new>`.").dimmed()
+    );
+}
+
+/// Groups (of two or more) of values that only differ by case,
+/// punctuation, a small typo, or that abbreviate one another
+/// (`WIP` / `work in progress`, and from `ABBREVIATIONS`, `WIP` / `in progress`)
+fn similar_groups<'a>(values: &[&'a String]) -> Vec<Vec<&'a String>> {
+    let norms: Vec<String> = values.iter().map(|v| normalize(v)).collect();
+    let initials: Vec<String> = values.iter().map(|v| initials(v)).collect();
+
+    // union-find over value indices
+    let mut parent: Vec<usize> = (0..values.len()).collect();
+    fn find(parent: &mut [usize], i: usize) -> usize {
+        let mut i = i;
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    for i in 0..values.len() {
+        for j in i + 1..values.len() {
+            let (a, b) = (&norms[i], &norms[j]);
+            if a.is_empty() || b.is_empty() {
+                continue;
+            }
+
+            let alike = a == b
+                || close_enough(a, b)
+                || abbreviates(a, b)
+                || (initials[i].len() > 1 && &initials[i] == b)
+                || (initials[j].len() > 1 && &initials[j] == a);
+
+            if alike {
+                let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                parent[rj] = ri;
+            }
+        }
+    }
+
+    let mut groups: BTreeMap<usize, Vec<&String>> = BTreeMap::new();
+    for (i, value) in values.iter().enumerate() {
+        let root = find(&mut parent, i);
+        groups.entry(root).or_default().push(value);
+    }
+
+    groups.into_values().filter(|g| g.len() > 1).collect()
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+/// Scalars as in `me show`; sequences contribute each item, mappings nothing
+fn flatten(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::Sequence(items) => items.iter().for_each(|i| flatten(i, out)),
+        Value::Tagged(t) => flatten(&t.value, out),
+        Value::Mapping(_) => {}
+        other => out.extend(render_inline_value(other)),
+    }
+}
+
+fn project_list(names: &[String]) -> String {
+    let mut list = names
+        .iter()
+        .take(LISTED_PROJECTS)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if names.len() > LISTED_PROJECTS {
+        list.push_str(&format!(", … (+{})", names.len() - LISTED_PROJECTS));
+    }
+
+    list
+}
+
+/// `In-Progress` and `in progress` both become `inprogress`
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// `work in progress` → `wip`
+fn initials(value: &str) -> String {
+    value
+        .split(|c: char| !c.is_alphanumeric())
+        .filter_map(|w| w.chars().next())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn abbreviates(a: &str, b: &str) -> bool {
+    ABBREVIATIONS
+        .iter()
+        .any(|&(short, long)| (a == short && b == long) || (a == long && b == short))
+}
+
+/// One typo for short words, two for longer ones
+fn close_enough(a: &str, b: &str) -> bool {
+    let shorter = a.chars().count().min(b.chars().count());
+    let allowed = match shorter {
+        0..=3 => return false,
+        4..=7 => 1,
+        _ => 2,
+    };
+
+    edit_distance(a, b) <= allowed
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            row.push((prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1));
+        }
+        prev = row;
+    }
+
+    prev[b.len()]
+}
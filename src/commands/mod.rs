@@ -8,6 +8,27 @@ pub mod sweep_cmd;
 pub mod keys_rename;
 pub mod push;
 pub mod brane;
+pub mod brane_repair;
+pub mod brane_name;
 pub mod checkout;
 pub mod register;
-pub mod prune;
\ No newline at end of file
+pub mod prune;
+pub mod sync_cmd;
+pub mod resolve_cmd;
+pub mod commit;
+pub mod doctor;
+pub mod tui;
+pub mod edit;
+pub mod completions;
+pub mod reindex;
+pub mod serve;
+pub mod rpc;
+pub mod watch;
+pub mod import_md;
+pub mod export_vault;
+pub mod show_template;
+pub mod site;
+pub mod diff_cmd;
+pub mod bulk;
+pub mod keys_values;
+pub mod values_replace;
\ No newline at end of file
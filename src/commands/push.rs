@@ -1,13 +1,15 @@
 use anyhow::Result;
 use std::fs;
 use std::io::{self, Read};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde_yaml::Value;
 use indexmap::IndexMap;
 
 use crate::core::Project;
-use crate::memfs;
+use crate::{git, memfs};
+use crate::utils::fsio::write_atomic;
+use crate::utils::resolve::check_project_name;
 use crate::utils::time::now_iso;
 
 /// Entry point
@@ -81,23 +83,44 @@ fn push_project(
     source: &str,
 ) -> Result<()> {
     let root = memfs::resolve_workspace_root()?;
-    let dest = memfs::projects_dir(&root)
-        .join(format!("{}.yaml", project_name));
 
-    if dest.exists() {
+    if memfs::projects_dir(&root).join(format!("{project_name}.yaml")).exists() {
         anyhow::bail!(
             "Project `{}` already exists. Use --as to choose a different name.",
             project_name
         );
     }
 
+    create_project(&root, data, project_name)?;
+
+    println!("✔ pushed {} → project `{}`", source, project_name);
+    Ok(())
+}
+
+/// Write a brand-new project: brane lock → materialize → write → git autocommit.
+/// Returns the path of the new file.
+pub fn create_project(
+    root: &Path,
+    data: Project,
+    project_name: &str,
+) -> Result<PathBuf> {
+    check_project_name(project_name)?;
+
+    let _lock = memfs::lock_brane(root)?;
+    let dest = memfs::projects_dir(root)
+        .join(format!("{}.yaml", project_name));
+
+    if dest.exists() {
+        anyhow::bail!("Project `{}` already exists.", project_name);
+    }
+
     // Materialize BEFORE writing
     let ordered = materialize_project(data, project_name);
 
-    fs::write(&dest, serde_yaml::to_string(&ordered)?)?;
+    write_atomic(&dest, serde_yaml::to_string(&ordered)?)?;
+    git::autocommit(root, std::slice::from_ref(&dest), &format!("push {project_name}"));
 
-    println!("✔ pushed {} → project `{}`", source, project_name);
-    Ok(())
+    Ok(dest)
 }
 
 // ------------------------------------------------------------
@@ -141,7 +164,7 @@ fn materialize_project(
 // Helpers
 // ------------------------------
 
-fn resolve_project_name(
+pub fn resolve_project_name(
     as_name: Option<&str>,
     data: &Project,
     file_path: Option<&Path>,
@@ -163,10 +186,9 @@ fn resolve_project_name(
     anyhow::bail!("Project name not specified (use `name:` or --as)")
 }
 
-fn slugify(input: &str) -> String {
+pub fn slugify(input: &str) -> String {
     input
         .trim()
         .to_lowercase()
-        .replace(' ', "-")
-        .replace('_', "-")
+        .replace([' ', '_'], "-")
 }
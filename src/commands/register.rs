@@ -4,6 +4,7 @@ use uuid::Uuid;
 
 use crate::memfs;
 use crate::global;
+use crate::utils::fsio::write_atomic;
 
 pub fn run() -> Result<()> {
     // Must be inside a membrane workspace
@@ -19,7 +20,7 @@ pub fn run() -> Result<()> {
         Ok(s) if !s.trim().is_empty() => s.trim().to_string(),
         _ => {
             let new_id = Uuid::new_v4().to_string();
-            fs::write(&id_path, &new_id)?;
+            write_atomic(&id_path, &new_id)?;
             new_id
         }
     };
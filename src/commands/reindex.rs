@@ -0,0 +1,21 @@
+use anyhow::Result;
+use std::time::Instant;
+
+use crate::{cache, memfs};
+
+/// Rebuild `.membrane/index.json` from scratch
+pub fn run() -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let dir = memfs::projects_dir(&root);
+
+    let start = Instant::now();
+    let index = cache::rebuild(&dir)?;
+
+    println!(
+        "✔ indexed {} project(s) in {:.2}s",
+        index.entries.len(),
+        start.elapsed().as_secs_f32()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anyhow::Result;
+use std::fs;
+
+use crate::memfs;
+use crate::sync;
+use crate::utils::project_writer::{materialize_project, write_project};
+use crate::utils::table::render_table;
+
+pub fn run(project: Option<&str>, ours: bool, theirs: bool) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+
+    let Some(project) = project else {
+        return list(&root);
+    };
+
+    if ours == theirs {
+        anyhow::bail!("Choose exactly one of --ours or --theirs");
+    }
+
+    let _lock = memfs::lock_brane(&root)?;
+
+    let conflicts = sync::load_conflicts(&root)?;
+    let conflict = conflicts
+        .into_iter()
+        .find(|c| c.project == project || c.id.starts_with(project))
+        .ok_or_else(|| anyhow::anyhow!("No sync conflict recorded for '{}'", project))?;
+
+    let mut data = conflict.merged.clone();
+
+    for (key, values) in &conflict.keys {
+        let pick = if theirs { &values.remote } else { &values.local };
+
+        match pick {
+            Some(v) => {
+                data.insert(key.clone(), v.clone());
+            }
+            None => {
+                data.shift_remove(key);
+            }
+        }
+    }
+
+    let path = memfs::projects_dir(&root).join(format!("{}.yaml", conflict.project));
+    let ordered = materialize_project(data, &conflict.project)?;
+    write_project(&path, ordered)?;
+
+    // The remote snapshot becomes the new baseline on both sides: whichever
+    // side syncs next sees only this change and carries the resolution across.
+    let mut base = sync::load_baseline(&root, &conflict.remote_brane)?;
+    base.insert(conflict.id.clone(), conflict.remote.clone());
+    sync::save_baseline(&root, &conflict.remote_brane, &base)?;
+
+    if memfs::projects_dir(&conflict.remote_root).is_dir() {
+        let local_id = memfs::read_brane_id(&root)?;
+        let _remote_lock = memfs::lock_brane(&conflict.remote_root)?;
+
+        let mut remote_base = sync::load_baseline(&conflict.remote_root, &local_id)?;
+        remote_base.insert(conflict.id.clone(), conflict.remote.clone());
+        sync::save_baseline(&conflict.remote_root, &local_id, &remote_base)?;
+    }
+
+    fs::remove_file(sync::conflict_path(&root, &conflict.project))?;
+
+    println!(
+        "✔ resolved `{}` ({})",
+        conflict.project,
+        if theirs { "kept theirs" } else { "kept ours" }
+    );
+    println!(
+        "Run `me sync {}` to propagate the resolution.",
+        conflict.remote_root.display()
+    );
+
+    Ok(())
+}
+
+fn list(root: &std::path::Path) -> Result<()> {
+    let conflicts = sync::load_conflicts(root)?;
+
+    if conflicts.is_empty() {
+        println!("No unresolved sync conflicts.");
+        return Ok(());
+    }
+
+    let rows = conflicts
+        .iter()
+        .map(|c| {
+            vec![
+                c.project.clone(),
+                c.keys.keys().cloned().collect::<Vec<_>>().join(", "),
+                c.remote_root.display().to_string(),
+            ]
+        })
+        .collect();
+
+    render_table("Sync Conflicts", &["Project", "Keys", "Remote"], rows, None);
+
+    println!("Resolve with `me resolve <project> --ours` or `--theirs`.");
+    Ok(())
+}
@@ -0,0 +1,363 @@
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::{json, Value as Json};
+use serde_yaml::Value;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use crate::core::Project;
+use crate::{global, memfs, sweep};
+use crate::commands::{push, set, unset};
+use crate::commands::show::{load_projects, sort_projects};
+use crate::utils::filter::{matches_all, parse_filter, Filter};
+use crate::utils::json::project_json;
+use crate::utils::resolve::{check_project_name, resolve_in_brane};
+
+// JSON-RPC 2.0 error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+// membrane error codes (server-defined range)
+const FAILED: i64 = -32000;
+const NOT_FOUND: i64 = -32001;
+
+/// Line-delimited JSON-RPC 2.0 on stdin/stdout.
+/// One request per line, one response per line; stdout carries nothing else.
+pub fn run() -> Result<()> {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout().lock();
+
+    for line in stdin.lock().lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(response) = handle_line(&line) {
+            writeln!(stdout, "{response}")?;
+            stdout.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Dispatch
+// ------------------------------------------------------------
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for RpcError {
+    fn from(e: anyhow::Error) -> Self {
+        Self::new(FAILED, e.to_string())
+    }
+}
+
+type RpcResult = std::result::Result<Json, RpcError>;
+
+/// Returns `None` for notifications (requests without an `id`)
+fn handle_line(line: &str) -> Option<Json> {
+    let request: Json = match serde_json::from_str(line) {
+        Ok(r) => r,
+        Err(e) => return Some(error_response(Json::Null, RpcError::new(PARSE_ERROR, e.to_string()))),
+    };
+
+    let id = request.get("id").cloned();
+    let method = request.get("method").and_then(Json::as_str);
+
+    let result = match method {
+        Some(method) if request.get("jsonrpc") == Some(&json!("2.0")) => {
+            let params = request.get("params").cloned().unwrap_or(json!({}));
+            dispatch(method, params)
+        }
+        // invalid requests always get an answer, with `"id": null` if none was readable
+        _ => {
+            return Some(error_response(
+                id.unwrap_or(Json::Null),
+                RpcError::new(
+                    INVALID_REQUEST,
+                    "expected {\"jsonrpc\": \"2.0\", \"method\": ..., \"id\": ...}",
+                ),
+            ))
+        }
+    };
+
+    let id = id?;
+
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => error_response(id, e),
+    })
+}
+
+fn error_response(id: Json, e: RpcError) -> Json {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": e.code, "message": e.message },
+    })
+}
+
+fn dispatch(method: &str, params: Json) -> RpcResult {
+    match method {
+        "branes.list" => branes_list(),
+        "projects.list" => projects_list(parse(params)?),
+        "projects.get" => projects_get(parse(params)?),
+        "projects.search" => projects_search(parse(params)?),
+        "projects.create" => projects_create(parse(params)?),
+        "keys.set" => keys_set(parse(params)?),
+        "keys.unset" => keys_unset(parse(params)?),
+        "keys.sweep" => keys_sweep(parse(params)?),
+        _ => Err(RpcError::new(METHOD_NOT_FOUND, format!("Unknown method `{method}`"))),
+    }
+}
+
+fn parse<T: for<'de> Deserialize<'de>>(params: Json) -> std::result::Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+// ------------------------------------------------------------
+// Params
+// ------------------------------------------------------------
+
+/// Every project method accepts an optional `brane` (path, alias or ID prefix)
+#[derive(Deserialize)]
+struct ListParams {
+    brane: Option<String>,
+    sort: Option<String>,
+    #[serde(default)]
+    desc: bool,
+    /// `key`, `key=value` or `key!=value`
+    #[serde(default, rename = "where")]
+    filters: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ProjectParams {
+    brane: Option<String>,
+    project: String,
+}
+
+#[derive(Deserialize)]
+struct SearchParams {
+    brane: Option<String>,
+    query: String,
+    /// Restrict the search to these keys
+    #[serde(default)]
+    keys: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct CreateParams {
+    brane: Option<String>,
+    name: Option<String>,
+    data: Project,
+}
+
+#[derive(Deserialize)]
+struct SetParams {
+    brane: Option<String>,
+    project: String,
+    key: String,
+    value: Value,
+}
+
+#[derive(Deserialize)]
+struct KeyParams {
+    brane: Option<String>,
+    project: String,
+    key: String,
+}
+
+#[derive(Deserialize)]
+struct SweepParams {
+    brane: Option<String>,
+    #[serde(default)]
+    similar: bool,
+}
+
+// ------------------------------------------------------------
+// Methods
+// ------------------------------------------------------------
+
+fn branes_list() -> RpcResult {
+    let index = global::load_global_index()?;
+
+    let branes: Vec<Json> = index
+        .workspaces
+        .iter()
+        .map(|w| {
+            json!({
+                "id": w.id,
+                "name": w.name,
+                "root": w.root,
+                "last_seen": w.last_seen,
+                "active": index.active.as_deref() == Some(w.id.as_str()),
+            })
+        })
+        .collect();
+
+    Ok(json!(branes))
+}
+
+fn projects_list(p: ListParams) -> RpcResult {
+    let root = brane_root(p.brane.as_deref())?;
+    let filters: Vec<Filter> = p
+        .filters
+        .iter()
+        .map(|f| parse_filter(f))
+        .collect::<Result<_>>()
+        .map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))?;
+
+    let mut projects = load_projects(&memfs::projects_dir(&root))?;
+    projects.retain(|(_, p)| matches_all(&filters, p));
+
+    if let Some(key) = &p.sort {
+        sort_projects(&mut projects, key, p.desc);
+    }
+
+    Ok(projects
+        .iter()
+        .map(|(name, project)| project_json(name, project))
+        .collect())
+}
+
+fn projects_get(p: ProjectParams) -> RpcResult {
+    let root = brane_root(p.brane.as_deref())?;
+    let (name, project) = resolve(&root, &p.project)?;
+    Ok(project_json(&name, &project))
+}
+
+/// Case-insensitive substring search over names and values
+fn projects_search(p: SearchParams) -> RpcResult {
+    let root = brane_root(p.brane.as_deref())?;
+    let needle = p.query.to_lowercase();
+
+    let mut hits = Vec::new();
+
+    for (name, project) in load_projects(&memfs::projects_dir(&root))? {
+        let matches: Vec<Json> = project
+            .iter()
+            .filter(|(k, _)| p.keys.is_empty() || p.keys.contains(k))
+            .filter(|(_, v)| value_contains(v, &needle))
+            .map(|(k, v)| json!({ "key": k, "value": v }))
+            .collect();
+
+        if !matches.is_empty() || (p.keys.is_empty() && name.to_lowercase().contains(&needle)) {
+            hits.push(json!({
+                "name": name,
+                "id": project.get("_id"),
+                "matches": matches,
+            }));
+        }
+    }
+
+    Ok(json!(hits))
+}
+
+fn projects_create(p: CreateParams) -> RpcResult {
+    let root = brane_root(p.brane.as_deref())?;
+    let name = push::resolve_project_name(p.name.as_deref(), &p.data, None)?;
+    check_project_name(&name).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))?;
+    push::create_project(&root, p.data, &name)?;
+
+    let (name, project) = resolve(&root, &name)?;
+    Ok(project_json(&name, &project))
+}
+
+fn keys_set(p: SetParams) -> RpcResult {
+    let root = brane_root(p.brane.as_deref())?;
+    let (name, _) = resolve(&root, &p.project)?;
+
+    set::set_value(&root, &name, &p.key, p.value)?;
+
+    let (name, project) = resolve(&root, &name)?;
+    Ok(project_json(&name, &project))
+}
+
+fn keys_unset(p: KeyParams) -> RpcResult {
+    let root = brane_root(p.brane.as_deref())?;
+    let (name, project) = resolve(&root, &p.project)?;
+
+    if !p.key.starts_with('_') && !project.contains_key(&p.key) {
+        return Err(RpcError::new(
+            NOT_FOUND,
+            format!("Key `{}` not found in project `{}`", p.key, name),
+        ));
+    }
+
+    unset::unset_value(&root, &name, &p.key)?;
+
+    let (name, project) = resolve(&root, &name)?;
+    Ok(project_json(&name, &project))
+}
+
+fn keys_sweep(p: SweepParams) -> RpcResult {
+    let root = brane_root(p.brane.as_deref())?;
+    let dir = memfs::projects_dir(&root);
+
+    if p.similar {
+        let groups: Vec<Vec<String>> = sweep::sweep_similar_keys(&dir)?
+            .into_values()
+            .map(|mut keys| {
+                keys.sort();
+                keys.dedup();
+                keys
+            })
+            .filter(|keys| keys.len() > 1)
+            .collect();
+
+        return Ok(json!({ "similar": groups }));
+    }
+
+    let keys: Vec<Json> = sweep::key_counts(&dir)?
+        .into_iter()
+        .map(|(key, count)| json!({ "key": key, "count": count }))
+        .collect();
+
+    Ok(json!({ "keys": keys }))
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+fn brane_root(target: Option<&str>) -> std::result::Result<PathBuf, RpcError> {
+    let root = match target {
+        Some(t) => memfs::resolve_brane_target(t),
+        None => memfs::resolve_workspace_root(),
+    };
+
+    root.map_err(|e| RpcError::new(NOT_FOUND, e.to_string()))
+}
+
+fn resolve(root: &Path, target: &str) -> std::result::Result<(String, Project), RpcError> {
+    resolve_in_brane(root, target).map_err(|e| RpcError::new(NOT_FOUND, e.to_string()))
+}
+
+fn value_contains(value: &Value, needle: &str) -> bool {
+    match value {
+        Value::String(s) => s.to_lowercase().contains(needle),
+        Value::Bool(_) | Value::Number(_) => serde_yaml::to_string(value)
+            .map(|s| s.trim().to_lowercase().contains(needle))
+            .unwrap_or(false),
+        Value::Sequence(items) => items.iter().any(|i| value_contains(i, needle)),
+        Value::Mapping(map) => map.values().any(|v| value_contains(v, needle)),
+        _ => false,
+    }
+}
@@ -0,0 +1,335 @@
+use anyhow::Result;
+use serde_json::{json, Value as Json};
+use std::path::Path;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::core::Project;
+use crate::{memfs, sweep};
+use crate::commands::{push, set, unset};
+use crate::commands::show::{load_projects, sort_projects};
+use crate::utils::filter::{matches_all, parse_filter, Filter};
+use crate::utils::json::project_json;
+use crate::utils::resolve::resolve_in_brane;
+
+/// Bundled browsing page served at `/`
+const INDEX_HTML: &str = include_str!("serve.html");
+
+/// Serve the active brane over HTTP on 127.0.0.1 only.
+/// Writes go through the same lock/materialize path as the CLI.
+/// Requests from other origins (or via a foreign `Host`, as in DNS rebinding) are refused.
+pub fn run(port: u16) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let addr = format!("127.0.0.1:{port}");
+
+    let server = Server::http(&addr)
+        .map_err(|e| anyhow::anyhow!("Cannot listen on {addr}: {e}"))?;
+
+    println!("✔ serving {} on http://{}", root.display(), addr);
+    println!("↪ Ctrl+C to stop.");
+
+    for request in server.incoming_requests() {
+        handle(&root, port, request);
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Routing
+// ------------------------------------------------------------
+
+/// Error carried back to the client as `{"error": ...}`
+struct ApiError {
+    status: u16,
+    message: String,
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(e: anyhow::Error) -> Self {
+        Self {
+            status: 400,
+            message: e.to_string(),
+        }
+    }
+}
+
+fn not_found(message: impl Into<String>) -> ApiError {
+    ApiError {
+        status: 404,
+        message: message.into(),
+    }
+}
+
+type ApiResult = std::result::Result<(u16, Json), ApiError>;
+
+fn handle(root: &Path, port: u16, mut request: Request) {
+    let method = request.method().clone();
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+
+    if let Err(e) = check_request(&request, port) {
+        println!("{} {} → {} ({})", method, path, e.status, e.message);
+        let response = Response::from_string(json!({ "error": e.message }).to_string())
+            .with_status_code(e.status)
+            .with_header(header("Content-Type", "application/json"));
+        let _ = request.respond(response);
+        return;
+    }
+
+    if method == Method::Get && (path == "/" || path == "/index.html") {
+        let response = Response::from_string(INDEX_HTML)
+            .with_header(header("Content-Type", "text/html; charset=utf-8"));
+        let _ = request.respond(response);
+        return;
+    }
+
+    let segments: Vec<String> = path
+        .trim_matches('/')
+        .split('/')
+        .map(|s| percent_decode(s, false))
+        .collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+    let query = parse_query(query);
+
+    let mut body = String::new();
+    let result = match request.as_reader().read_to_string(&mut body) {
+        Err(e) => Err(anyhow::anyhow!("Unreadable request body: {e}").into()),
+        Ok(_) => route(root, &method, &segments, &query, &body),
+    };
+
+    let (status, payload) = match result {
+        Ok(ok) => ok,
+        Err(e) => (e.status, json!({ "error": e.message })),
+    };
+
+    println!("{} {} → {}", method, path, status);
+
+    let response = Response::from_string(payload.to_string())
+        .with_status_code(status)
+        .with_header(header("Content-Type", "application/json"));
+    let _ = request.respond(response);
+}
+
+fn route(
+    root: &Path,
+    method: &Method,
+    segments: &[&str],
+    query: &[(String, String)],
+    body: &str,
+) -> ApiResult {
+    match (method, segments) {
+        (Method::Get, ["api", "projects"]) => list_projects(root, query),
+        (Method::Post, ["api", "projects"]) => create_project(root, query, body),
+        (Method::Get, ["api", "projects", target]) => get_project(root, target),
+        (Method::Put, ["api", "projects", target, "keys", key]) => {
+            set_key(root, target, key, body)
+        }
+        (Method::Delete, ["api", "projects", target, "keys", key]) => {
+            unset_key(root, target, key)
+        }
+        (Method::Get, ["api", "keys"]) => key_stats(root),
+        _ => Err(not_found(format!("No route for {} /{}", method, segments.join("/")))),
+    }
+}
+
+// ------------------------------------------------------------
+// Handlers
+// ------------------------------------------------------------
+
+/// `GET /api/projects?sort=key&desc=1&where=key=value&fields=a,b`
+fn list_projects(root: &Path, query: &[(String, String)]) -> ApiResult {
+    let filters: Vec<Filter> = query_all(query, "where")
+        .map(parse_filter)
+        .collect::<Result<_>>()?;
+
+    let fields: Vec<&str> = query_all(query, "fields")
+        .flat_map(|f| f.split(','))
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .collect();
+
+    let mut projects = load_projects(&memfs::projects_dir(root))?;
+    projects.retain(|(_, p)| matches_all(&filters, p));
+
+    if let Some(key) = query_one(query, "sort") {
+        let desc = query_one(query, "desc").is_some_and(|d| d != "0" && d != "false");
+        sort_projects(&mut projects, key, desc);
+    }
+
+    let items: Vec<Json> = projects
+        .into_iter()
+        .map(|(name, project)| {
+            if fields.is_empty() {
+                return project_json(&name, &project);
+            }
+
+            let picked: Project = project
+                .into_iter()
+                .filter(|(k, _)| k == "_id" || fields.contains(&k.as_str()))
+                .collect();
+            project_json(&name, &picked)
+        })
+        .collect();
+
+    Ok((200, json!({ "count": items.len(), "projects": items })))
+}
+
+/// `GET /api/projects/<name or ID prefix>`
+fn get_project(root: &Path, target: &str) -> ApiResult {
+    let (name, project) = resolve(root, target)?;
+    Ok((200, project_json(&name, &project)))
+}
+
+/// `POST /api/projects[?name=...]` with a JSON object body
+fn create_project(root: &Path, query: &[(String, String)], body: &str) -> ApiResult {
+    let data: Project = serde_json::from_str(body)
+        .map_err(|e| anyhow::anyhow!("Body must be a JSON object: {e}"))?;
+
+    let name = push::resolve_project_name(query_one(query, "name"), &data, None)?;
+    let path = push::create_project(root, data, &name)?;
+
+    Ok((201, project_json(&name, &read_written(&path)?)))
+}
+
+/// `PUT /api/projects/<target>/keys/<key>` with any JSON value as body
+fn set_key(root: &Path, target: &str, key: &str, body: &str) -> ApiResult {
+    let (name, _) = resolve(root, target)?;
+
+    let value: Json = serde_json::from_str(body)
+        .map_err(|e| anyhow::anyhow!("Body must be a JSON value: {e}"))?;
+    let value = serde_yaml::to_value(value)
+        .map_err(|e| anyhow::anyhow!("Unsupported value: {e}"))?;
+
+    set::set_value(root, &name, key, value)?;
+    get_project(root, &name)
+}
+
+/// `DELETE /api/projects/<target>/keys/<key>`
+fn unset_key(root: &Path, target: &str, key: &str) -> ApiResult {
+    let (name, project) = resolve(root, target)?;
+
+    if !key.starts_with('_') && !project.contains_key(key) {
+        return Err(not_found(format!("Key `{key}` not found in project `{name}`")));
+    }
+
+    unset::unset_value(root, &name, key)?;
+    get_project(root, &name)
+}
+
+/// `GET /api/keys`: usage count per key, most used first
+fn key_stats(root: &Path) -> ApiResult {
+    let keys: Vec<Json> = sweep::key_counts(&memfs::projects_dir(root))?
+        .into_iter()
+        .map(|(key, count)| json!({ "key": key, "count": count }))
+        .collect();
+
+    Ok((200, json!({ "keys": keys })))
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+/// Only same-origin clients may talk to the server, and writes must be JSON:
+/// a foreign page can neither reach us through a rebound host name nor send
+/// a "simple" cross-site form/text request that skips the CORS preflight.
+fn check_request(request: &Request, port: u16) -> std::result::Result<(), ApiError> {
+    let forbidden = |message: String| ApiError { status: 403, message };
+    let get = |name: &'static str| {
+        request
+            .headers()
+            .iter()
+            .find(|h| h.field.equiv(name))
+            .map(|h| h.value.as_str().trim().to_string())
+    };
+
+    let allowed_hosts = [format!("127.0.0.1:{port}"), format!("localhost:{port}")];
+
+    match get("Host") {
+        Some(host) if allowed_hosts.contains(&host) => {}
+        other => return Err(forbidden(format!("Host `{}` not allowed", other.unwrap_or_default()))),
+    }
+
+    if let Some(origin) = get("Origin") {
+        if !allowed_hosts.iter().any(|h| origin == format!("http://{h}")) {
+            return Err(forbidden(format!("Origin `{origin}` not allowed")));
+        }
+    }
+
+    let mutating = matches!(request.method(), Method::Post | Method::Put | Method::Delete);
+    let json = get("Content-Type").is_some_and(|ct| {
+        ct.split(';').next().is_some_and(|m| m.trim().eq_ignore_ascii_case("application/json"))
+    });
+
+    if mutating && !json {
+        return Err(ApiError {
+            status: 415,
+            message: "Writes require `Content-Type: application/json`".into(),
+        });
+    }
+
+    Ok(())
+}
+
+fn resolve(root: &Path, target: &str) -> std::result::Result<(String, Project), ApiError> {
+    resolve_in_brane(root, target).map_err(|e| not_found(e.to_string()))
+}
+
+fn read_written(path: &Path) -> Result<Project> {
+    Ok(serde_yaml::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("static header is valid")
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|pair| {
+            let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(k, true), percent_decode(v, true))
+        })
+        .collect()
+}
+
+fn query_one<'a>(query: &'a [(String, String)], key: &'a str) -> Option<&'a str> {
+    query_all(query, key).next()
+}
+
+fn query_all<'a>(
+    query: &'a [(String, String)],
+    key: &'a str,
+) -> impl Iterator<Item = &'a str> {
+    query
+        .iter()
+        .filter(move |(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+/// Decode `%XX` escapes; `+` means space only inside query strings
+fn percent_decode(input: &str, plus_as_space: bool) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match (bytes[i], escaped) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 2;
+            }
+            (b'+', _) if plus_as_space => out.push(b' '),
+            (b, _) => out.push(b),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&out).into_owned()
+}
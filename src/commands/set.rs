@@ -1,12 +1,14 @@
 use anyhow::Result;
 use std::fs;
+use std::path::Path;
 
 use crate::core::Project;
-use crate::memfs;
+use crate::{git, memfs};
 use crate::utils::{
     parse::parse_scalar,
     input::read_multiline,
-    project_writer::{materialize_project, write_project}
+    project_writer::{materialize_project, write_project},
+    resolve::check_project_name,
 };
 
 pub fn run(project: &str, key: &str, value: Option<&str>) -> Result<()> {
@@ -22,9 +24,6 @@ pub fn run(project: &str, key: &str, value: Option<&str>) -> Result<()> {
         anyhow::bail!("Project not found: {project}");
     }
 
-    let content = fs::read_to_string(&path)?;
-    let mut data: Project = serde_yaml::from_str(&content)?;
-
     // --- Determine value
     let yaml_value = match value {
         Some(v) => parse_scalar(v),
@@ -37,12 +36,40 @@ pub fn run(project: &str, key: &str, value: Option<&str>) -> Result<()> {
         }
     };
 
-    data.insert(key.to_string(), yaml_value);
-    let ordered = materialize_project(data, project)?;
-    write_project(&path, ordered)?;
-
+    set_value(&root, project, key, yaml_value)?;
 
     println!("Set `{key}` on `{project}`");
 
     Ok(())
 }
+
+/// Set one key through the canonical write path:
+/// brane lock → materialize → write → git autocommit
+pub fn set_value(
+    root: &Path,
+    project: &str,
+    key: &str,
+    value: serde_yaml::Value,
+) -> Result<()> {
+    if key.starts_with('_') {
+        anyhow::bail!("Keys starting with '_' are reserved metadata keys.");
+    }
+    check_project_name(project)?;
+
+    let _lock = memfs::lock_brane(root)?;
+    let path = memfs::projects_dir(root).join(format!("{project}.yaml"));
+
+    if !path.exists() {
+        anyhow::bail!("Project not found: {project}");
+    }
+
+    let content = fs::read_to_string(&path)?;
+    let mut data: Project = serde_yaml::from_str(&content)?;
+
+    data.insert(key.to_string(), value);
+    let ordered = materialize_project(data, project)?;
+    write_project(&path, ordered)?;
+    git::autocommit(root, &[path], &format!("set {key} on {project}"));
+
+    Ok(())
+}
@@ -1,12 +1,13 @@
 use crate::core::Project;
-use crate::memfs;
-use crate::global;
+use crate::{cache, memfs};
 use crate::utils::render::render_key_value;
 use crate::utils::resolve::resolve_project;
-use crate::commands::show_model::ShowContext;
+use crate::utils::fsio::write_atomic;
+use crate::commands::show_model::{Highlights, ShowContext};
+use crate::commands::show_template::render_template;
 
 use indexmap::IndexSet;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
 use serde_yaml::Value;
 use std::cmp::Ordering;
@@ -24,17 +25,25 @@ pub fn run(
     printed: bool,
     only: bool,
     fields: Vec<String>,
+    template: Option<&str>,
 ) -> Result<()> {
     let root = memfs::resolve_workspace_root()?;
     let projects_dir = memfs::projects_dir(&root);
 
-    let index = global::load_global_index();
-    let brane_id = index
-        .active
-        .clone()
-        .unwrap_or_else(|| "unknown".into());
+    let brane_id = memfs::read_brane_id(&root)
+        .unwrap_or_else(|_| "unknown".into());
 
-    let mut projects = load_projects(&projects_dir)?;
+    // list views are served from the index cache; exports need full projects
+    let cached = if printed {
+        None
+    } else {
+        cache::listing(&projects_dir, sort_key)?
+    };
+
+    let mut projects = match cached {
+        Some(p) => p,
+        None => load_projects(&projects_dir)?,
+    };
 
     if let Some(k) = sort_key {
         sort_projects(&mut projects, k, desc);
@@ -46,26 +55,7 @@ pub fn run(
         }
     }
 
-    // -----------------------------
-    // Normalize fields
-    // -----------------------------
-
-    let mut set: IndexSet<String> = fields
-        .into_iter()
-        .map(|f| f.trim().to_string())
-        .filter(|f| !f.is_empty())
-        .collect();
-
-    let mut normalized_fields = Vec::new();
-
-    // Always force sort key to be first
-    if let Some(sort) = sort_key {
-        normalized_fields.push(sort.to_string());
-        set.shift_remove(sort); // prevents duplication
-    }
-
-    // Preserve user order exactly
-    normalized_fields.extend(set.into_iter());
+    let normalized_fields = normalize_fields(fields, sort_key);
 
     let ctx = ShowContext {
         brane_root: root.clone(),
@@ -78,7 +68,10 @@ pub fn run(
     render_cli(&ctx, project)?;
 
     if printed {
-        render_markdown(&ctx)?;
+        match template {
+            Some(name) => render_template(&ctx, name)?,
+            None => render_markdown(&ctx)?,
+        }
     }
 
     Ok(())
@@ -100,7 +93,7 @@ fn render_cli(ctx: &ShowContext, project: Option<&str>) -> Result<()> {
 
     match project {
         Some(p) => render_single_cli(ctx, p),
-        None => render_list_cli(ctx),
+        None => render_list_cli(ctx, &Highlights::default()),
     }
 }
 
@@ -108,7 +101,7 @@ fn render_cli(ctx: &ShowContext, project: Option<&str>) -> Result<()> {
 // LIST VIEW (projection-aware)
 // ------------------------------------------------------------
 
-fn render_list_cli(ctx: &ShowContext) -> Result<()> {
+pub fn render_list_cli(ctx: &ShowContext, highlights: &Highlights) -> Result<()> {
     let header = match &ctx.sort_key {
         Some(k) => format!("=== Projects (sorted by {}) ===", k),
         None => "=== Projects ===".to_string(),
@@ -123,36 +116,46 @@ fn render_list_cli(ctx: &ShowContext) -> Result<()> {
             .unwrap_or("");
 
         let short_id = id.chars().take(8).collect::<String>();
+        let changed = highlights.keys.get(name);
+
+        if highlights.added.contains(name) {
+            print!("{} {:<20}", "+".green().bold(), name.green().bold());
+        } else if changed.is_some() {
+            print!("{} {:<20}", "~".yellow().bold(), name.yellow().bold());
+        } else {
+            print!("• {:<20}", name.bright_white());
+        }
 
-        print!("• {:<20}", name.bright_white());
+        let shown: Vec<&String> = if !ctx.fields.is_empty() {
+            ctx.fields.iter().collect()
+        } else {
+            ctx.sort_key.iter().collect()
+        };
 
-        if !ctx.fields.is_empty() {
-            for field in &ctx.fields {
-                let val = project
-                    .get(field)
-                    .and_then(render_inline_value)
-                    .unwrap_or("—".into());
+        for field in shown {
+            let val = project
+                .get(field)
+                .and_then(render_inline_value)
+                .unwrap_or("—".into());
 
+            if changed.is_some_and(|keys| keys.contains(field)) {
+                print!(" {}: {:<15}", field.yellow(), val.yellow());
+            } else {
                 print!(
                     " {}: {:<15}",
                     field.dimmed(),
                     val.dimmed()
                 );
             }
-        } else if let Some(k) = &ctx.sort_key {
-            let val = project
-                .get(k)
-                .and_then(render_inline_value)
-                .unwrap_or("—".into());
+        }
+
+        print!(" {}", format!("[{}]", short_id).dimmed());
 
-            print!(
-                " {}: {:<15}",
-                k.dimmed(),
-                val.dimmed(),
-            );
+        if let Some(keys) = changed {
+            print!("  {}", format!("changed: {}", keys.join(", ")).yellow());
         }
 
-        println!(" {}", format!("[{}]", short_id).dimmed());
+        println!();
     }
 
     Ok(())
@@ -166,6 +169,11 @@ fn render_single_cli(ctx: &ShowContext, input: &str) -> Result<()> {
     let dir = memfs::projects_dir(&ctx.brane_root);
     let (name, project) = resolve_project(&dir, input)?;
 
+    render_project_cli(&name, &project, &Highlights::default())
+}
+
+/// One project, key by key; metadata keys are dimmed
+pub fn render_project_cli(name: &str, project: &Project, highlights: &Highlights) -> Result<()> {
     println!(
         "{}",
         format!("— {} —", name)
@@ -173,20 +181,36 @@ fn render_single_cli(ctx: &ShowContext, input: &str) -> Result<()> {
             .bold()
     );
 
+    let changed = highlights.keys.get(name);
+    let added = highlights.added.contains(name);
+
     for (key, value) in project {
-        match value {
+        let hit = added || changed.is_some_and(|keys| keys.contains(key));
+
+        let scalar = match value {
             Value::Bool(_)
             | Value::Number(_)
             | Value::String(_)
-            | Value::Null => {
-                let rendered = serde_yaml::to_string(&value)?.trim().to_string();
-                let (k, v) = render_key_value(&key, &rendered);
+            | Value::Null => Some(serde_yaml::to_string(value)?.trim().to_string()),
+            _ => None,
+        };
+
+        match scalar {
+            Some(v) if hit => {
+                println!("{} {}: {}", "~".yellow().bold(), key.yellow().bold(), v.yellow());
+            }
+            Some(v) => {
+                let (k, v) = render_key_value(key, &v);
                 println!("{k}: {v}");
             }
-            _ => {
-                let (k, _) = render_key_value(&key, "");
-                println!("{k}:");
-                let rendered = serde_yaml::to_string(&value)?;
+            None => {
+                if hit {
+                    println!("{} {}:", "~".yellow().bold(), key.yellow().bold());
+                } else {
+                    let (k, _) = render_key_value(key, "");
+                    println!("{k}:");
+                }
+                let rendered = serde_yaml::to_string(value)?;
                 for line in rendered.lines() {
                     println!("  {}", line);
                 }
@@ -194,6 +218,11 @@ fn render_single_cli(ctx: &ShowContext, input: &str) -> Result<()> {
         }
     }
 
+    // keys that disappeared since the last render
+    for key in changed.into_iter().flatten().filter(|k| !project.contains_key(*k)) {
+        println!("{} {}", "-".red().bold(), key.red().strikethrough());
+    }
+
     Ok(())
 }
 
@@ -273,7 +302,7 @@ fn render_markdown(ctx: &ShowContext) -> Result<()> {
     }
 
     let filename = format!("BRANE_{}.md", short);
-    fs::write(&filename, md)?;
+    write_atomic(Path::new(&filename), md)?;
 
     println!("✔ wrote {}", filename);
 
@@ -284,7 +313,28 @@ fn render_markdown(ctx: &ShowContext) -> Result<()> {
 // Helpers
 // ------------------------------------------------------------
 
-fn render_inline_value(value: &Value) -> Option<String> {
+/// Trim and dedupe `-f` fields, forcing the sort key first
+pub fn normalize_fields(fields: Vec<String>, sort_key: Option<&str>) -> Vec<String> {
+    let mut set: IndexSet<String> = fields
+        .into_iter()
+        .map(|f| f.trim().to_string())
+        .filter(|f| !f.is_empty())
+        .collect();
+
+    let mut normalized_fields = Vec::new();
+
+    // Always force sort key to be first
+    if let Some(sort) = sort_key {
+        normalized_fields.push(sort.to_string());
+        set.shift_remove(sort); // prevents duplication
+    }
+
+    // Preserve user order exactly
+    normalized_fields.extend(set);
+    normalized_fields
+}
+
+pub fn render_inline_value(value: &Value) -> Option<String> {
     match value {
         Value::Bool(_)
         | Value::Number(_)
@@ -301,7 +351,7 @@ fn render_inline_value(value: &Value) -> Option<String> {
     }
 }
 
-fn load_projects(dir: &Path) -> Result<Vec<(String, Project)>> {
+pub fn load_projects(dir: &Path) -> Result<Vec<(String, Project)>> {
     let mut out = Vec::new();
 
     for entry in fs::read_dir(dir)? {
@@ -319,12 +369,13 @@ fn load_projects(dir: &Path) -> Result<Vec<(String, Project)>> {
             .to_string();
 
         let content = fs::read_to_string(&path)?;
-        let mut project: Project = serde_yaml::from_str(&content)?;
+        let mut project: Project = serde_yaml::from_str(&content)
+            .with_context(|| format!("{} is not a valid project (try `me doctor`)", path.display()))?;
 
         if !project.contains_key("_id") {
             let id = uuid::Uuid::new_v4().to_string();
             project.insert("_id".into(), Value::String(id));
-            fs::write(&path, serde_yaml::to_string(&project)?)?;
+            write_atomic(&path, serde_yaml::to_string(&project)?)?;
         }
 
         out.push((name, project));
@@ -333,8 +384,8 @@ fn load_projects(dir: &Path) -> Result<Vec<(String, Project)>> {
     Ok(out)
 }
 
-fn sort_projects(
-    projects: &mut Vec<(String, Project)>,
+pub fn sort_projects(
+    projects: &mut [(String, Project)],
     key: &str,
     desc: bool,
 ) {
@@ -346,7 +397,7 @@ fn sort_projects(
     });
 }
 
-fn compare_yaml_values(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+pub fn compare_yaml_values(a: Option<&Value>, b: Option<&Value>) -> Ordering {
     match (a, b) {
         (None, None) => Ordering::Equal,
         (None, Some(_)) => Ordering::Greater,
@@ -1,4 +1,6 @@
 use crate::core::Project;
+use indexmap::IndexMap;
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 #[derive(Debug)]
@@ -9,3 +11,11 @@ pub struct ShowContext {
     pub projects: Vec<(String, Project)>,
     pub fields: Vec<String>,
 }
+
+/// Projects and keys to call out when rendering (`me watch`); empty for `me show`
+#[derive(Debug, Default)]
+pub struct Highlights {
+    pub added: HashSet<String>,
+    /// project name → keys whose value changed, appeared or vanished
+    pub keys: IndexMap<String, Vec<String>>,
+}
@@ -0,0 +1,119 @@
+use anyhow::Result;
+use minijinja::{Environment, UndefinedBehavior};
+use serde_json::{json, Value as Json};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::show::render_inline_value;
+use crate::commands::show_model::ShowContext;
+use crate::utils::fsio::write_atomic;
+
+/// Suffix stripped from the template name to get the output file name
+const TEMPLATE_SUFFIX: &str = ".tmpl";
+
+pub fn templates_dir(root: &Path) -> PathBuf {
+    root.join(".membrane").join("templates").join("export")
+}
+
+/// Render `ShowContext` through `.membrane/templates/export/<name>`
+/// and write the result to `<name without .tmpl>` in the current directory.
+pub fn render_template(ctx: &ShowContext, name: &str) -> Result<()> {
+    let dir = templates_dir(&ctx.brane_root);
+    let path = dir.join(name);
+
+    if !path.is_file() {
+        let available = list_templates(&dir);
+        if available.is_empty() {
+            anyhow::bail!("Template not found: {} (no templates in {})", name, dir.display());
+        }
+        anyhow::bail!("Template not found: {} (available: {})", name, available.join(", "));
+    }
+
+    let source = fs::read_to_string(&path)?;
+
+    let mut env = Environment::new();
+    env.set_undefined_behavior(UndefinedBehavior::Chainable);
+    env.set_keep_trailing_newline(true);
+    env.add_filter("yaml", yaml_filter);
+    env.add_template(name, &source)
+        .map_err(|e| anyhow::anyhow!("{:#}", e))?;
+
+    let rendered = env
+        .get_template(name)
+        .and_then(|t| t.render(template_context(ctx)))
+        .map_err(|e| anyhow::anyhow!("{:#}", e))?;
+
+    let filename = Path::new(name)
+        .file_name()
+        .and_then(|f| f.to_str())
+        .map(|f| f.strip_suffix(TEMPLATE_SUFFIX).unwrap_or(f))
+        .unwrap_or(name)
+        .to_string();
+
+    write_atomic(Path::new(&filename), rendered)?;
+    println!("✔ wrote {}", filename);
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+/// What templates see:
+/// `brane.{id,short_id,root}`, `sort_key`, `fields`,
+/// and `projects` as `{name, id, short_id, keys, inline}` in display order
+fn template_context(ctx: &ShowContext) -> Json {
+    let projects: Vec<Json> = ctx
+        .projects
+        .iter()
+        .map(|(name, project)| {
+            let id = project.get("_id").and_then(|v| v.as_str()).unwrap_or("");
+
+            // scalar values pre-rendered the way the CLI list shows them
+            let inline: serde_json::Map<String, Json> = project
+                .iter()
+                .filter_map(|(k, v)| render_inline_value(v).map(|s| (k.clone(), Json::String(s))))
+                .collect();
+
+            json!({
+                "name": name,
+                "id": id,
+                "short_id": id.chars().take(8).collect::<String>(),
+                "keys": project,
+                "inline": inline,
+            })
+        })
+        .collect();
+
+    json!({
+        "brane": {
+            "id": ctx.brane_id,
+            "short_id": ctx.brane_id.chars().take(8).collect::<String>(),
+            "root": ctx.brane_root,
+        },
+        "sort_key": ctx.sort_key,
+        "fields": ctx.fields,
+        "projects": projects,
+    })
+}
+
+/// `{{ value | yaml }}`: any value as YAML text
+fn yaml_filter(value: minijinja::Value) -> Result<String, minijinja::Error> {
+    serde_yaml::to_string(&value)
+        .map(|s| s.trim_end().to_string())
+        .map_err(|e| minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, e.to_string()))
+}
+
+fn list_templates(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .filter_map(|e| e.file_name().to_str().map(str::to_string))
+        .collect();
+
+    names.sort();
+    names
+}
@@ -0,0 +1,429 @@
+use anyhow::Result;
+use serde_yaml::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use crate::core::Project;
+use crate::{config, memfs, sweep};
+use crate::commands::export_vault::{ids_in, is_reference_key, link_targets, project_id};
+use crate::commands::show::{load_projects, render_inline_value, sort_projects};
+use crate::utils::fsio::write_atomic;
+
+/// Most-used user keys shown as columns on the index
+const INDEX_COLUMNS: usize = 5;
+
+/// Key whose values are grouped on the tags page
+const TAGS_KEY: &str = "tags";
+
+/// Written into every generated site; only such directories are ever cleared
+const MARKER_FILE: &str = ".membrane-site";
+
+const STYLE: &str = "\
+body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 60rem; color: #222; padding: 0 1rem; }
+h1, h2 { color: #ff69b4; }
+nav a { margin-right: 1rem; }
+a { color: #c2185b; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: .3rem .6rem; border-bottom: 1px solid #eee; vertical-align: top; }
+th a { color: inherit; }
+.dim { color: #999; }
+pre { background: #fafafa; border: 1px solid #eee; padding: .5rem; margin: 0; white-space: pre-wrap; }
+";
+
+/// Generate a read-only static site of the active brane in `outdir`
+pub fn build(outdir: &str) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let dir = memfs::projects_dir(&root);
+    let out = Path::new(outdir);
+
+    let mut projects = load_projects(&dir)?;
+    projects.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let brane_id = memfs::read_brane_id(&root).unwrap_or_else(|_| "unknown".into());
+    let key_counts = sweep::key_counts(&dir)?;
+    let site = Site {
+        title: format!("Brane {}", brane_id.chars().take(8).collect::<String>()),
+        links: link_targets(&projects),
+        references: config::load_config(&root)?.references,
+        pages: unique_slugs(projects.iter().map(|(n, _)| n)),
+        sort_pages: unique_slugs(key_counts.iter().map(|(k, _)| k)),
+        key_counts,
+        has_tags: projects.iter().any(|(_, p)| p.contains_key(TAGS_KEY)),
+    };
+
+    prepare_outdir(out)?;
+
+    write_atomic(&out.join("style.css"), STYLE)?;
+
+    let columns = site.columns();
+    write_atomic(&out.join("index.html"), site.index_page(&projects, &columns, None, ""))?;
+
+    let mut pages = 0;
+    for (key, _) in &site.key_counts {
+        for desc in [false, true] {
+            let mut sorted = projects.clone();
+            sort_projects(&mut sorted, key, desc);
+
+            let html = site.index_page(&sorted, &columns, Some((key, desc)), "../");
+            write_atomic(&out.join("sort").join(site.sort_file(key, desc)), html)?;
+            pages += 1;
+        }
+    }
+
+    let backlinks = site.backlinks(&projects);
+    for (name, project) in &projects {
+        let html = site.project_page(name, project, backlinks.get(name));
+        write_atomic(&out.join("p").join(format!("{}.html", site.pages[name])), html)?;
+    }
+
+    write_atomic(&out.join("keys.html"), site.keys_page())?;
+
+    if site.has_tags {
+        write_atomic(&out.join("tags.html"), site.tags_page(&projects))?;
+    }
+
+    println!(
+        "✔ built site for {} project(s) in {} ({} sorted views)",
+        projects.len(),
+        out.display(),
+        pages
+    );
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Pages
+// ------------------------------------------------------------
+
+struct Site {
+    title: String,
+    /// name or full `_id` → project name
+    links: HashMap<String, String>,
+    /// `references:` from the brane config (same linking rule as `me export-vault`)
+    references: Vec<String>,
+    /// project name → page file stem (unique even when names slugify alike)
+    pages: HashMap<String, String>,
+    /// key → sort page file stem
+    sort_pages: HashMap<String, String>,
+    /// (key, project count), most used first
+    key_counts: Vec<(String, usize)>,
+    has_tags: bool,
+}
+
+impl Site {
+    /// Most-used user keys
+    fn columns(&self) -> Vec<String> {
+        self.key_counts
+            .iter()
+            .map(|(k, _)| k)
+            .filter(|k| !k.starts_with('_') && k.as_str() != "name")
+            .take(INDEX_COLUMNS)
+            .cloned()
+            .collect()
+    }
+
+    fn index_page(
+        &self,
+        projects: &[(String, Project)],
+        columns: &[String],
+        sorted: Option<(&str, bool)>,
+        base: &str,
+    ) -> String {
+        let mut cols: Vec<String> = columns.to_vec();
+        if let Some((key, _)) = sorted {
+            if !cols.iter().any(|c| c == key) {
+                cols.insert(0, key.to_string());
+            }
+        }
+
+        let mut body = String::new();
+
+        let heading = match sorted {
+            Some((key, desc)) => format!(
+                "Projects <span class=\"dim\">sorted by {}{}</span>",
+                escape(key),
+                if desc { " (desc)" } else { "" }
+            ),
+            None => "Projects".to_string(),
+        };
+        body.push_str(&format!("<h1>{}</h1>\n<table>\n<tr>", heading));
+
+        body.push_str(&format!("<th>{}</th>", self.sort_header("name", sorted, base)));
+        for col in &cols {
+            body.push_str(&format!("<th>{}</th>", self.sort_header(col, sorted, base)));
+        }
+        body.push_str("<th class=\"dim\">ID</th></tr>\n");
+
+        for (name, project) in projects {
+            body.push_str(&format!("<tr><td>{}</td>", self.project_link(name, &format!("{base}p/"))));
+
+            for col in &cols {
+                let cell = project
+                    .get(col)
+                    .and_then(render_inline_value)
+                    .map(|v| escape(&v))
+                    .unwrap_or_else(|| {
+                        if project.contains_key(col) { "…".into() } else { "—".into() }
+                    });
+                body.push_str(&format!("<td>{}</td>", cell));
+            }
+
+            let short: String = project_id(project).chars().take(8).collect();
+            body.push_str(&format!("<td class=\"dim\">{}</td></tr>\n", short));
+        }
+
+        body.push_str("</table>\n");
+        self.layout("Projects", &body, base)
+    }
+
+    /// Column header linking to the ascending view, or flipping the current one
+    fn sort_header(&self, key: &str, sorted: Option<(&str, bool)>, base: &str) -> String {
+        let (desc, arrow) = match sorted {
+            Some((k, d)) if k == key => (!d, if d { " ▼" } else { " ▲" }),
+            _ => (false, ""),
+        };
+
+        if !self.key_counts.iter().any(|(k, _)| k == key) {
+            return escape(key);
+        }
+
+        format!(
+            "<a href=\"{}sort/{}\">{}</a>{}",
+            base,
+            self.sort_file(key, desc),
+            escape(key),
+            arrow
+        )
+    }
+
+    fn project_page(&self, name: &str, project: &Project, linked_from: Option<&Vec<String>>) -> String {
+        let mut body = format!("<h1>{}</h1>\n<table>\n", escape(name));
+
+        for (key, value) in project {
+            body.push_str(&format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                escape(key),
+                self.render_value(value, is_reference_key(key, &self.references))
+            ));
+        }
+        body.push_str("</table>\n");
+
+        if let Some(from) = linked_from {
+            body.push_str("<h2>Linked from</h2>\n<ul>\n");
+            for other in from {
+                body.push_str(&format!("<li>{}</li>\n", self.project_link(other, "")));
+            }
+            body.push_str("</ul>\n");
+        }
+
+        self.layout(name, &body, "../")
+    }
+
+    fn keys_page(&self) -> String {
+        let mut body = String::from("<h1>Key usage</h1>\n<table>\n<tr><th>Key</th><th>Projects</th></tr>\n");
+
+        for (key, count) in &self.key_counts {
+            body.push_str(&format!(
+                "<tr><td><a href=\"sort/{}\">{}</a></td><td>{}</td></tr>\n",
+                self.sort_file(key, false),
+                escape(key),
+                count
+            ));
+        }
+
+        body.push_str("</table>\n");
+        self.layout("Keys", &body, "")
+    }
+
+    fn tags_page(&self, projects: &[(String, Project)]) -> String {
+        let mut tags: BTreeMap<String, Vec<&String>> = BTreeMap::new();
+
+        for (name, project) in projects {
+            let values = match project.get(TAGS_KEY) {
+                Some(Value::Sequence(items)) => items.iter().filter_map(render_inline_value).collect(),
+                Some(v) => render_inline_value(v).into_iter().collect(),
+                None => Vec::new(),
+            };
+
+            for tag in values {
+                tags.entry(tag).or_default().push(name);
+            }
+        }
+
+        let mut body = String::from("<h1>Tags</h1>\n");
+
+        for (tag, names) in &tags {
+            body.push_str(&format!("<h2 id=\"{0}\">{0}</h2>\n<ul>\n", escape(tag)));
+            for name in names {
+                body.push_str(&format!("<li>{}</li>\n", self.project_link(name, "p/")));
+            }
+            body.push_str("</ul>\n");
+        }
+
+        self.layout("Tags", &body, "")
+    }
+
+    fn project_link(&self, name: &str, prefix: &str) -> String {
+        format!("<a href=\"{}{}.html\">{}</a>", prefix, self.pages[name], escape(name))
+    }
+
+    fn sort_file(&self, key: &str, desc: bool) -> String {
+        format!("{}{}.html", self.sort_pages[key], if desc { ".desc" } else { "" })
+    }
+
+    fn layout(&self, title: &str, body: &str, base: &str) -> String {
+        let tags = if self.has_tags {
+            format!("<a href=\"{base}tags.html\">Tags</a>")
+        } else {
+            String::new()
+        };
+
+        format!(
+            "<!doctype html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{title} · {site}</title>\n<link rel=\"stylesheet\" href=\"{base}style.css\">\n</head>\n<body>\n\
+             <nav><a href=\"{base}index.html\">Projects</a><a href=\"{base}keys.html\">Keys</a>{tags}\
+             <span class=\"dim\">{site}</span></nav>\n{body}</body>\n</html>\n",
+            title = escape(title),
+            site = escape(&self.title),
+        )
+    }
+
+    /// Scalars inline, the rest as YAML. With `linked`, strings naming a
+    /// project and full IDs inside text link to the project page.
+    fn render_value(&self, value: &Value, linked: bool) -> String {
+        match value {
+            Value::String(s) => match self.links.get(s.trim()).filter(|_| linked) {
+                Some(name) => self.project_link(name, ""),
+                None => {
+                    let mut html = escape(s).replace('\n', "<br>");
+                    if linked {
+                        for (id, name) in ids_in(s, &self.links) {
+                            html = html.replace(id, &self.project_link(name, ""));
+                        }
+                    }
+                    html
+                }
+            },
+            Value::Sequence(items) if items.iter().all(|i| render_inline_value(i).is_some()) => items
+                .iter()
+                .map(|i| self.render_value(i, linked))
+                .collect::<Vec<_>>()
+                .join(", "),
+            _ => match render_inline_value(value) {
+                Some(v) => escape(&v),
+                None => format!(
+                    "<pre>{}</pre>",
+                    escape(serde_yaml::to_string(value).unwrap_or_default().trim_end())
+                ),
+            },
+        }
+    }
+
+    /// project name → names of the projects whose values point at it
+    fn backlinks(&self, projects: &[(String, Project)]) -> HashMap<String, Vec<String>> {
+        let mut out: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (name, project) in projects {
+            let mut targets = Vec::new();
+            for (key, value) in project {
+                if is_reference_key(key, &self.references) {
+                    self.collect_targets(value, &mut targets);
+                }
+            }
+
+            targets.sort();
+            targets.dedup();
+
+            for target in targets.into_iter().filter(|t| t != name) {
+                out.entry(target).or_default().push(name.clone());
+            }
+        }
+
+        out
+    }
+
+    fn collect_targets(&self, value: &Value, out: &mut Vec<String>) {
+        match value {
+            Value::String(s) => match self.links.get(s.trim()) {
+                Some(name) => out.push(name.clone()),
+                None => out.extend(ids_in(s, &self.links).into_iter().map(|(_, n)| n.to_string())),
+            },
+            Value::Sequence(items) => items.iter().for_each(|i| self.collect_targets(i, out)),
+            Value::Mapping(map) => map.values().for_each(|v| self.collect_targets(v, out)),
+            _ => {}
+        }
+    }
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+/// Keys can hold anything; keep file names portable
+fn page_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// `page_name` for each name, with `-2`, `-3`, ... appended where two names
+/// slugify alike (compared case-insensitively, for case-insensitive filesystems)
+fn unique_slugs<'a>(names: impl Iterator<Item = &'a String>) -> HashMap<String, String> {
+    let mut names: Vec<&String> = names.collect();
+    names.sort();
+
+    let mut taken = std::collections::HashSet::new();
+    let mut out = HashMap::new();
+
+    for name in names {
+        let base = page_name(name);
+        let mut slug = base.clone();
+        let mut n = 1;
+
+        while !taken.insert(slug.to_lowercase()) {
+            n += 1;
+            slug = format!("{base}-{n}");
+        }
+
+        out.insert(name.clone(), slug);
+    }
+
+    out
+}
+
+/// Clear `p/` and `sort/` of a previous build so deleted projects and keys vanish.
+/// Refuses a non-empty directory that was not generated by `me site build`.
+fn prepare_outdir(out: &Path) -> Result<()> {
+    let marker = out.join(MARKER_FILE);
+
+    if out.exists() && !marker.exists() && fs::read_dir(out)?.next().is_some() {
+        anyhow::bail!(
+            "{} is not empty and was not created by `me site build`; choose an empty or new directory",
+            out.display()
+        );
+    }
+
+    if marker.exists() {
+        for sub in ["p", "sort"] {
+            let path = out.join(sub);
+            if path.is_dir() {
+                fs::remove_dir_all(&path)?;
+            }
+        }
+    }
+
+    fs::create_dir_all(out.join("p"))?;
+    fs::create_dir_all(out.join("sort"))?;
+    write_atomic(&marker, "generated by `me site build`; this directory may be cleared on rebuild\n")?;
+
+    Ok(())
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,14 +1,22 @@
 use anyhow::Result;
+use colored::Colorize;
 use std::collections::HashSet;
+use std::path::Path;
 
 use crate::{memfs, sweep};
+use crate::commands::show::load_projects;
 use crate::utils::table::render_table;
 
-pub fn run(similar: bool) -> Result<()> {
+/// Example projects listed per type
+const TYPE_EXAMPLES: usize = 3;
+
+pub fn run(similar: bool, types: bool) -> Result<()> {
     let root = memfs::resolve_workspace_root()?;
     let dir = memfs::projects_dir(&root);
 
-    if similar {
+    if types {
+        show_types(&dir)?;
+    } else if similar {
         let groups = sweep::sweep_similar_keys(&dir)?;
         println!("Possible duplicate keys:\n");
 
@@ -20,19 +28,11 @@ pub fn run(similar: bool) -> Result<()> {
             }
         }
     } else {
-        let counts = sweep::sweep_keys(&dir)?;
-
-        // Convert to rows
-        let mut rows: Vec<Vec<String>> = counts
+        let rows: Vec<Vec<String>> = sweep::key_counts(&dir)?
             .into_iter()
             .map(|(k, c)| vec![k, c.to_string()])
             .collect();
 
-        // Optional: sort by count desc, then key
-        rows.sort_by(|a, b| {
-            b[1].cmp(&a[1]).then_with(|| a[0].cmp(&b[0]))
-        });
-
         render_table(
             "Key Usage",
             &["Key", "Count"],
@@ -43,3 +43,80 @@ pub fn run(similar: bool) -> Result<()> {
 
     Ok(())
 }
+
+// ------------------------------------------------------------
+// --types
+// ------------------------------------------------------------
+
+fn show_types(dir: &Path) -> Result<()> {
+    let projects = load_projects(dir)?;
+    let types = sweep::sweep_key_types(&projects);
+
+    // most used first, like `me keys`; reserved `_` keys are always typed the same
+    let mut keys: Vec<(&String, usize)> = types
+        .iter()
+        .filter(|(key, _)| !key.starts_with('_'))
+        .map(|(key, by_type)| (key, by_type.values().map(Vec::len).sum()))
+        .collect();
+    keys.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut drift: Vec<&String> = Vec::new();
+
+    for (key, count) in keys {
+        let by_type = &types[key];
+        let summary = by_type
+            .iter()
+            .map(|(ty, names)| format!("{} ({})", ty, names.len()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let flag = if by_type.len() > 1 {
+            drift.push(key);
+            "⚠ mixed"
+        } else {
+            ""
+        };
+
+        rows.push(vec![key.clone(), count.to_string(), summary, flag.to_string()]);
+    }
+
+    render_table("Key Types", &["Key", "Count", "Types", ""], rows, None);
+
+    if drift.is_empty() {
+        println!("✔ every key has a single type across the brane");
+        return Ok(());
+    }
+
+    println!(
+        "\n{}",
+        format!("⚠️  {} key(s) hold values of different types:", drift.len())
+            .yellow()
+            .bold()
+    );
+
+    for key in drift {
+        println!("\n  {}", key.bold());
+
+        for (ty, names) in &types[key] {
+            let mut examples = names
+                .iter()
+                .take(TYPE_EXAMPLES)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ");
+            if names.len() > TYPE_EXAMPLES {
+                examples.push_str(&format!(", … (+{})", names.len() - TYPE_EXAMPLES));
+            }
+
+            println!("    {:<9} {:>3}  {}", ty, names.len(), examples.dimmed());
+        }
+    }
+
+    println!(
+        "\n{}",
+        "Sorting on these keys falls back to text order; align the values with `me set`.".dimmed()
+    );
+
+    Ok(())
+}
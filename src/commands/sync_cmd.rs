@@ -0,0 +1,253 @@
+use anyhow::Result;
+use colored::Colorize;
+use indexmap::{IndexMap, IndexSet};
+use serde_yaml::Value;
+use std::path::PathBuf;
+
+use crate::core::Project;
+use crate::memfs;
+use crate::sync::{self, Baseline, Direction, SyncConflict};
+use crate::commands::show::load_projects;
+use crate::utils::project_writer::{materialize_project, write_project};
+
+/// One side of a sync: a brane root with its projects indexed by `_id`
+struct Side {
+    root: PathBuf,
+    id: String,
+    projects: IndexMap<String, (String, Project)>,
+}
+
+impl Side {
+    fn load(root: PathBuf) -> Result<Self> {
+        let id = memfs::read_brane_id(&root)?;
+        let mut projects = IndexMap::new();
+
+        for (name, project) in load_projects(&memfs::projects_dir(&root))? {
+            if let Some(pid) = project.get("_id").and_then(|v| v.as_str()) {
+                projects.insert(pid.to_string(), (name, project));
+            }
+        }
+
+        Ok(Self { root, id, projects })
+    }
+
+    fn path_for(&self, name: &str) -> PathBuf {
+        memfs::projects_dir(&self.root).join(format!("{name}.yaml"))
+    }
+}
+
+#[derive(Default)]
+struct Summary {
+    pushed: usize,
+    pulled: usize,
+    merged: usize,
+    deleted: usize,
+    conflicts: usize,
+    skipped: usize,
+}
+
+pub fn run(other: &str) -> Result<()> {
+    let local_root = memfs::resolve_workspace_root()?;
+    let remote_root = memfs::resolve_brane_target(other)?;
+
+    if local_root.canonicalize()? == remote_root.canonicalize()? {
+        anyhow::bail!("Cannot sync a brane with itself");
+    }
+
+    let _local_lock = memfs::lock_brane(&local_root)?;
+    let _remote_lock = memfs::lock_brane(&remote_root)?;
+
+    let local = Side::load(local_root)?;
+    let remote = Side::load(remote_root)?;
+
+    let base = sync::load_baseline(&local.root, &remote.id)?;
+    let mut next_base: Baseline = IndexMap::new();
+    let mut summary = Summary::default();
+
+    println!(
+        "Syncing {} ⇄ {}",
+        local.root.display(),
+        remote.root.display()
+    );
+    println!();
+
+    let ids: IndexSet<String> = local
+        .projects
+        .keys()
+        .chain(remote.projects.keys())
+        .cloned()
+        .collect();
+
+    for id in ids {
+        match (local.projects.get(&id), remote.projects.get(&id)) {
+            (Some((name, project)), None) => {
+                match one_sided(name, project, &local, &remote, base.get(&id))? {
+                    OneSided::Copied => {
+                        println!("→ {}", name);
+                        summary.pushed += 1;
+                        next_base.insert(id, project.clone());
+                    }
+                    OneSided::Deleted => {
+                        println!("{} {} (deleted on the other side)", "×".red(), name);
+                        summary.deleted += 1;
+                    }
+                    OneSided::Skipped => summary.skipped += 1,
+                }
+            }
+
+            (None, Some((name, project))) => {
+                match one_sided(name, project, &remote, &local, base.get(&id))? {
+                    OneSided::Copied => {
+                        println!("← {}", name);
+                        summary.pulled += 1;
+                        next_base.insert(id, project.clone());
+                    }
+                    OneSided::Deleted => {
+                        println!("{} {} (deleted here)", "×".red(), name);
+                        summary.deleted += 1;
+                    }
+                    OneSided::Skipped => summary.skipped += 1,
+                }
+            }
+
+            (Some((lname, lp)), Some((rname, rp))) => match sync::direction(base.get(&id), lp, rp) {
+                Direction::InSync => {
+                    next_base.insert(id, lp.clone());
+                }
+
+                Direction::Merge => {
+                    let b = base.get(&id).cloned().unwrap_or_default();
+                    let (merged, keys) = sync::merge3(&b, lp, rp);
+
+                    if !keys.is_empty() {
+                        let conflict = SyncConflict {
+                            id: id.clone(),
+                            project: lname.clone(),
+                            remote_root: remote.root.clone(),
+                            remote_brane: remote.id.clone(),
+                            merged,
+                            remote: rp.clone(),
+                            keys,
+                        };
+
+                        let listed = conflict.keys.keys().cloned().collect::<Vec<_>>().join(", ");
+                        sync::save_conflict(&local.root, &conflict)?;
+                        println!("{} {} ({})", "✗".red(), lname, listed);
+                        summary.conflicts += 1;
+
+                        // keep the old baseline so `me resolve` can settle it
+                        if !b.is_empty() {
+                            next_base.insert(id, b);
+                        }
+                        continue;
+                    }
+
+                    let merged = materialize_project(merged, lname)?;
+                    let mut remote_copy = merged.clone();
+                    remote_copy.insert("name".into(), Value::String(rname.clone()));
+
+                    write_project(&local.path_for(lname), merged.clone())?;
+                    write_project(&remote.path_for(rname), remote_copy)?;
+
+                    println!("⇄ {}", lname);
+                    summary.merged += 1;
+                    next_base.insert(id, merged);
+                }
+
+                Direction::Push => {
+                    let mut copy = lp.clone();
+                    copy.insert("name".into(), Value::String(rname.clone()));
+                    write_project(&remote.path_for(rname), copy)?;
+
+                    println!("→ {}", lname);
+                    summary.pushed += 1;
+                    next_base.insert(id, lp.clone());
+                }
+
+                Direction::Pull => {
+                    let mut copy = rp.clone();
+                    copy.insert("name".into(), Value::String(lname.clone()));
+                    write_project(&local.path_for(lname), copy)?;
+
+                    println!("← {}", lname);
+                    summary.pulled += 1;
+                    next_base.insert(id, rp.clone());
+                }
+            },
+
+            (None, None) => unreachable!(),
+        }
+    }
+
+    sync::save_baseline(&local.root, &remote.id, &next_base)?;
+    sync::save_baseline(&remote.root, &local.id, &next_base)?;
+
+    println!();
+    println!(
+        "Done. {} pushed, {} pulled, {} merged, {} deleted, {} conflict(s), {} skipped.",
+        summary.pushed,
+        summary.pulled,
+        summary.merged,
+        summary.deleted,
+        summary.conflicts,
+        summary.skipped,
+    );
+
+    if summary.conflicts > 0 {
+        println!("Run `me resolve` to review conflicts.");
+    }
+
+    Ok(())
+}
+
+// ---------- helpers ----------
+
+enum OneSided {
+    Copied,
+    Deleted,
+    Skipped,
+}
+
+/// A project present on `from` only. If the baseline shows the other side
+/// deleted it, delete it here too (unless it changed since); otherwise copy it over.
+fn one_sided(
+    name: &str,
+    project: &Project,
+    from: &Side,
+    to: &Side,
+    base: Option<&Project>,
+) -> Result<OneSided> {
+    if sync::deleted_on_other_side(base, project) {
+        std::fs::remove_file(from.path_for(name))?;
+        return Ok(OneSided::Deleted);
+    }
+
+    if base.is_some() {
+        println!(
+            "{} {} (deleted on the other side but changed here, keeping it)",
+            "!".yellow(),
+            name
+        );
+    }
+
+    Ok(if copy_into(to, name, project)? {
+        OneSided::Copied
+    } else {
+        OneSided::Skipped
+    })
+}
+
+/// Copy a project that only exists on one side. Returns false on a name clash.
+fn copy_into(dest: &Side, name: &str, project: &Project) -> Result<bool> {
+    if dest.path_for(name).exists() {
+        println!(
+            "{} {} (name already used by another project on the other side)",
+            "!".yellow(),
+            name
+        );
+        return Ok(false);
+    }
+
+    write_project(&dest.path_for(name), project.clone())?;
+    Ok(true)
+}
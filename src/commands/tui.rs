@@ -0,0 +1,530 @@
+use anyhow::Result;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use serde_yaml::Value;
+use std::path::PathBuf;
+
+use crate::commands::set::set_value;
+use crate::commands::show::{load_projects, render_inline_value, sort_projects};
+use crate::core::Project;
+use crate::{memfs, sweep};
+use crate::utils::parse::parse_scalar;
+
+const PINK: Color = Color::Rgb(255, 105, 180);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Focus {
+    List,
+    Detail,
+    Keys,
+}
+
+enum Mode {
+    Normal,
+    Filter,
+    Edit { key: String, buffer: String },
+}
+
+struct App {
+    root: PathBuf,
+    projects: Vec<(String, Project)>,
+    /// Indices into `projects` that pass the filter
+    visible: Vec<usize>,
+    keys: Vec<(String, usize)>,
+    filter: String,
+    sort_key: Option<String>,
+    desc: bool,
+    focus: Focus,
+    mode: Mode,
+    list: ListState,
+    detail_idx: usize,
+    keys_state: ListState,
+    status: String,
+    quit: bool,
+}
+
+// ------------------------------------------------------------
+// Public entry
+// ------------------------------------------------------------
+
+pub fn run(sort_key: Option<&str>, desc: bool) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let mut app = App::new(root, sort_key.map(str::to_string), desc)?;
+
+    let mut terminal = ratatui::init();
+    let result = app.event_loop(&mut terminal);
+    ratatui::restore();
+
+    result
+}
+
+// ------------------------------------------------------------
+// STATE
+// ------------------------------------------------------------
+
+impl App {
+    fn new(root: PathBuf, sort_key: Option<String>, desc: bool) -> Result<Self> {
+        let mut app = Self {
+            root,
+            projects: Vec::new(),
+            visible: Vec::new(),
+            keys: Vec::new(),
+            filter: String::new(),
+            sort_key,
+            desc,
+            focus: Focus::List,
+            mode: Mode::Normal,
+            list: ListState::default(),
+            detail_idx: 0,
+            keys_state: ListState::default(),
+            status: String::new(),
+            quit: false,
+        };
+
+        app.reload()?;
+        app.list.select(Some(0));
+        app.keys_state.select(Some(0));
+
+        Ok(app)
+    }
+
+    /// Re-read projects and key counts from disk, keeping the selection
+    fn reload(&mut self) -> Result<()> {
+        let selected = self.selected().map(|(name, _)| name.clone());
+        let dir = memfs::projects_dir(&self.root);
+
+        self.projects = load_projects(&dir)?;
+        self.projects.sort_by(|a, b| a.0.cmp(&b.0));
+        if let Some(k) = &self.sort_key {
+            sort_projects(&mut self.projects, k, self.desc);
+        }
+
+        self.keys = sweep::key_counts(&dir)?;
+
+        self.apply_filter();
+
+        if let Some(name) = selected {
+            if let Some(pos) = self.visible.iter().position(|&i| self.projects[i].0 == name) {
+                self.list.select(Some(pos));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_filter(&mut self) {
+        let needle = self.filter.to_lowercase();
+
+        self.visible = self
+            .projects
+            .iter()
+            .enumerate()
+            .filter(|(_, (name, project))| matches(name, project, &needle))
+            .map(|(i, _)| i)
+            .collect();
+
+        let max = self.visible.len().saturating_sub(1);
+        let pos = self.list.selected().unwrap_or(0).min(max);
+        self.list.select(Some(pos));
+        self.detail_idx = 0;
+    }
+
+    fn selected(&self) -> Option<&(String, Project)> {
+        let pos = self.list.selected()?;
+        self.visible.get(pos).map(|&i| &self.projects[i])
+    }
+
+    fn selected_key(&self) -> Option<(&String, &Value)> {
+        self.selected()?.1.get_index(self.detail_idx)
+    }
+
+    // ---------- input ----------
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while !self.quit {
+            terminal.draw(|f| self.draw(f))?;
+
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    self.handle_key(key);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+
+        match &mut self.mode {
+            Mode::Filter => match key.code {
+                KeyCode::Char(c) => {
+                    self.filter.push(c);
+                    self.apply_filter();
+                }
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                    self.apply_filter();
+                }
+                KeyCode::Esc => {
+                    self.filter.clear();
+                    self.apply_filter();
+                    self.mode = Mode::Normal;
+                }
+                KeyCode::Enter => self.mode = Mode::Normal,
+                _ => {}
+            },
+
+            Mode::Edit { buffer, .. } => match key.code {
+                KeyCode::Char(c) => buffer.push(c),
+                KeyCode::Backspace => {
+                    buffer.pop();
+                }
+                KeyCode::Esc => {
+                    self.mode = Mode::Normal;
+                    self.status = "Edit cancelled.".into();
+                }
+                KeyCode::Enter => self.save_edit(),
+                _ => {}
+            },
+
+            Mode::Normal => self.handle_normal(key.code),
+        }
+    }
+
+    fn handle_normal(&mut self, code: KeyCode) {
+        self.status.clear();
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Char('/') => self.mode = Mode::Filter,
+            KeyCode::Tab => self.focus = next_focus(self.focus),
+            KeyCode::BackTab => {
+                self.focus = next_focus(next_focus(self.focus));
+            }
+            KeyCode::Char('r') => self.refresh("Reloaded."),
+            KeyCode::Char('d') => {
+                self.desc = !self.desc;
+                self.refresh("");
+            }
+            KeyCode::Char('x') => {
+                self.sort_key = None;
+                self.refresh("Sorting cleared.");
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(1),
+            KeyCode::Enter | KeyCode::Char('e') => self.activate(),
+            _ => {}
+        }
+    }
+
+    fn move_cursor(&mut self, delta: isize) {
+        let step = |pos: usize, len: usize| -> usize {
+            if len == 0 {
+                return 0;
+            }
+            (pos as isize + delta).clamp(0, len as isize - 1) as usize
+        };
+
+        match self.focus {
+            Focus::List => {
+                let pos = step(self.list.selected().unwrap_or(0), self.visible.len());
+                self.list.select(Some(pos));
+                self.detail_idx = 0;
+            }
+            Focus::Detail => {
+                let len = self.selected().map(|(_, p)| p.len()).unwrap_or(0);
+                self.detail_idx = step(self.detail_idx, len);
+            }
+            Focus::Keys => {
+                let pos = step(self.keys_state.selected().unwrap_or(0), self.keys.len());
+                self.keys_state.select(Some(pos));
+            }
+        }
+    }
+
+    fn activate(&mut self) {
+        match self.focus {
+            Focus::List => self.focus = Focus::Detail,
+
+            Focus::Detail => {
+                let Some((key, value)) = self.selected_key() else {
+                    return;
+                };
+
+                if key.starts_with('_') || key == "name" {
+                    self.status = format!("`{key}` is managed by Membrane and cannot be edited.");
+                    return;
+                }
+
+                match render_inline_value(value) {
+                    Some(rendered) if !rendered.contains('\n') => {
+                        self.mode = Mode::Edit {
+                            key: key.clone(),
+                            buffer: rendered,
+                        };
+                    }
+                    _ => {
+                        self.status = format!("`{key}` is not a single-line scalar; use `me set`.");
+                    }
+                }
+            }
+
+            Focus::Keys => {
+                let Some(key) = self
+                    .keys_state
+                    .selected()
+                    .and_then(|i| self.keys.get(i))
+                    .map(|(k, _)| k.clone())
+                else {
+                    return;
+                };
+
+                if self.sort_key.as_deref() == Some(key.as_str()) {
+                    self.desc = !self.desc;
+                } else {
+                    self.sort_key = Some(key);
+                    self.desc = false;
+                }
+                self.refresh("");
+            }
+        }
+    }
+
+    fn save_edit(&mut self) {
+        let Mode::Edit { key, buffer } = std::mem::replace(&mut self.mode, Mode::Normal) else {
+            return;
+        };
+        let Some(name) = self.selected().map(|(n, _)| n.clone()) else {
+            return;
+        };
+
+        match set_value(&self.root, &name, &key, parse_scalar(&buffer)) {
+            Ok(()) => self.refresh(&format!("✔ set `{key}` on `{name}`")),
+            Err(e) => self.status = format!("✗ {e}"),
+        }
+    }
+
+    fn refresh(&mut self, message: &str) {
+        self.status = match self.reload() {
+            Ok(()) => message.to_string(),
+            Err(e) => format!("✗ {e}"),
+        };
+    }
+
+    // ---------- drawing ----------
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let outer = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(frame.area());
+
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(30),
+                Constraint::Percentage(45),
+                Constraint::Percentage(25),
+            ])
+            .split(outer[0]);
+
+        self.draw_list(frame, panes[0]);
+        self.draw_detail(frame, panes[1]);
+        self.draw_keys(frame, panes[2]);
+        self.draw_status(frame, outer[1]);
+    }
+
+    fn block(&self, title: String, pane: Focus) -> Block<'static> {
+        let border = if self.focus == pane {
+            Style::default().fg(PINK)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border)
+            .title(Span::styled(title, Style::default().fg(PINK).add_modifier(Modifier::BOLD)))
+    }
+
+    fn draw_list(&mut self, frame: &mut Frame, area: Rect) {
+        let title = match &self.sort_key {
+            Some(k) => format!(" Projects ({}) · {} {} ", self.visible.len(), k, arrow(self.desc)),
+            None => format!(" Projects ({}) ", self.visible.len()),
+        };
+
+        let items: Vec<ListItem> = self
+            .visible
+            .iter()
+            .map(|&i| {
+                let (name, project) = &self.projects[i];
+                let mut spans = vec![Span::raw(name.clone())];
+
+                if let Some(k) = &self.sort_key {
+                    let val = project
+                        .get(k)
+                        .and_then(render_inline_value)
+                        .unwrap_or("—".into());
+                    spans.push(Span::styled(
+                        format!("  {k}: {val}"),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(self.block(title, Focus::List))
+            .highlight_style(Style::default().fg(PINK).add_modifier(Modifier::BOLD))
+            .highlight_symbol("• ");
+
+        frame.render_stateful_widget(list, area, &mut self.list);
+    }
+
+    fn draw_detail(&self, frame: &mut Frame, area: Rect) {
+        let Some((name, project)) = self.selected() else {
+            let empty = Paragraph::new("No projects match.").block(self.block(" — ".into(), Focus::Detail));
+            frame.render_widget(empty, area);
+            return;
+        };
+
+        let mut lines = Vec::new();
+        let mut selected_line = 0;
+
+        for (i, (key, value)) in project.iter().enumerate() {
+            let is_meta = key.starts_with('_');
+            let is_selected = self.focus == Focus::Detail && i == self.detail_idx;
+
+            let mut style = if is_meta {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+            if is_selected {
+                style = style.add_modifier(Modifier::REVERSED);
+                selected_line = lines.len();
+            }
+
+            match render_inline_value(value) {
+                Some(rendered) if !rendered.contains('\n') => {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{key}: "), style.add_modifier(Modifier::BOLD)),
+                        Span::styled(rendered, style),
+                    ]));
+                }
+                _ => {
+                    lines.push(Line::from(Span::styled(
+                        format!("{key}:"),
+                        style.add_modifier(Modifier::BOLD),
+                    )));
+                    let rendered = serde_yaml::to_string(value).unwrap_or_default();
+                    for line in rendered.lines() {
+                        lines.push(Line::from(Span::styled(format!("  {line}"), style)));
+                    }
+                }
+            }
+        }
+
+        // keep the selected key in view
+        let height = area.height.saturating_sub(2) as usize;
+        let scroll = (selected_line + 1).saturating_sub(height);
+
+        let detail = Paragraph::new(lines)
+            .block(self.block(format!(" {name} "), Focus::Detail))
+            .scroll((scroll as u16, 0));
+
+        frame.render_widget(detail, area);
+    }
+
+    fn draw_keys(&mut self, frame: &mut Frame, area: Rect) {
+        let width = self.keys.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+
+        let items: Vec<ListItem> = self
+            .keys
+            .iter()
+            .map(|(k, c)| {
+                let marker = if self.sort_key.as_deref() == Some(k.as_str()) {
+                    arrow(self.desc)
+                } else {
+                    " "
+                };
+                let style = if k.starts_with('_') {
+                    Style::default().fg(Color::DarkGray)
+                } else {
+                    Style::default()
+                };
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{k:<width$} "), style),
+                    Span::styled(format!("{c:>3} {marker}"), Style::default().fg(Color::DarkGray)),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(self.block(" Keys ".into(), Focus::Keys))
+            .highlight_style(Style::default().fg(PINK).add_modifier(Modifier::BOLD));
+
+        frame.render_stateful_widget(list, area, &mut self.keys_state);
+    }
+
+    fn draw_status(&self, frame: &mut Frame, area: Rect) {
+        let line = match &self.mode {
+            Mode::Filter => Line::from(vec![
+                Span::styled("/", Style::default().fg(PINK)),
+                Span::raw(format!("{}▏", self.filter)),
+            ]),
+            Mode::Edit { key, buffer } => Line::from(vec![
+                Span::styled(format!("{key} = "), Style::default().fg(PINK)),
+                Span::raw(format!("{buffer}▏")),
+                Span::styled("  enter save · esc cancel", Style::default().fg(Color::DarkGray)),
+            ]),
+            Mode::Normal if !self.status.is_empty() => Line::from(self.status.clone()),
+            Mode::Normal => Line::from(Span::styled(
+                "q quit · / filter · tab pane · ↑↓ move · enter edit/sort · d reverse · x unsort · r reload",
+                Style::default().fg(Color::DarkGray),
+            )),
+        };
+
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+/// Case-insensitive match on the project name or any scalar value
+fn matches(name: &str, project: &Project, needle: &str) -> bool {
+    if needle.is_empty() || name.to_lowercase().contains(needle) {
+        return true;
+    }
+
+    project
+        .values()
+        .filter_map(render_inline_value)
+        .any(|v| v.to_lowercase().contains(needle))
+}
+
+fn next_focus(focus: Focus) -> Focus {
+    match focus {
+        Focus::List => Focus::Detail,
+        Focus::Detail => Focus::Keys,
+        Focus::Keys => Focus::List,
+    }
+}
+
+fn arrow(desc: bool) -> &'static str {
+    if desc { "▼" } else { "▲" }
+}
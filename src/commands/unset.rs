@@ -1,19 +1,32 @@
 use anyhow::Result;
 use std::fs;
+use std::path::Path;
 
 use crate::core::Project;
-use crate::memfs;
+use crate::{git, memfs};
 use crate::utils::project_writer::{materialize_project, write_project};
+use crate::utils::resolve::check_project_name;
 
 
 /// Remove a key from a project
 pub fn run(project: &str, key: &str) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    unset_value(&root, project, key)?;
+
+    println!("✔ removed key `{}` from `{}`", key, project);
+    Ok(())
+}
+
+/// Remove one key through the canonical write path:
+/// brane lock → materialize → write → git autocommit
+pub fn unset_value(root: &Path, project: &str, key: &str) -> Result<()> {
     if key.starts_with('_') {
         anyhow::bail!("Refusing to delete reserved metadata key `{}`", key);
     }
+    check_project_name(project)?;
 
-    let root = memfs::resolve_workspace_root()?;
-    let path = memfs::projects_dir(&root).join(format!("{project}.yaml"));
+    let _lock = memfs::lock_brane(root)?;
+    let path = memfs::projects_dir(root).join(format!("{project}.yaml"));
 
     if !path.exists() {
         anyhow::bail!("Project not found: {project}");
@@ -29,8 +42,7 @@ pub fn run(project: &str, key: &str) -> Result<()> {
     // update timestamp
     let ordered = materialize_project(data, project)?;
     write_project(&path, ordered)?;
+    git::autocommit(root, &[path], &format!("unset {key} on {project}"));
 
-
-    println!("✔ removed key `{}` from `{}`", key, project);
     Ok(())
 }
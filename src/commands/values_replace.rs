@@ -0,0 +1,199 @@
+use anyhow::Result;
+use colored::Colorize;
+use regex::Regex;
+use serde_yaml::Value;
+use std::path::PathBuf;
+
+use crate::{git, memfs};
+use crate::commands::show::{load_projects, render_inline_value};
+use crate::utils::filter::{matches_all, parse_filter, Filter, FilterOp};
+use crate::utils::parse::parse_scalar;
+use crate::utils::project_writer::{materialize_project, write_project};
+
+/// How `old` is compared against a value
+pub enum Pattern {
+    /// The whole value: the plain text of strings, other scalars as rendered by `me show`
+    Exact(String),
+    /// A regex; every match is replaced (`$1` etc. refer to capture groups)
+    Regex(Regex),
+}
+
+impl Pattern {
+    pub fn new(old: &str, regex: bool) -> Result<Self> {
+        if !regex {
+            return Ok(Pattern::Exact(old.to_string()));
+        }
+
+        Regex::new(old)
+            .map(Pattern::Regex)
+            .map_err(|e| anyhow::anyhow!("Invalid regex `{}`: {}", old, e))
+    }
+
+    /// The replacement for one scalar, or None when it doesn't match
+    fn apply(&self, value: &Value, new: &str) -> Option<Value> {
+        // strings compare as plain text, not with the quotes YAML needs for `'wip: later'`
+        let current = match value {
+            Value::String(s) => s.clone(),
+            other => render_inline_value(other)?,
+        };
+
+        match self {
+            Pattern::Exact(old) => (current == *old).then(|| parse_scalar(new)),
+            Pattern::Regex(re) => re
+                .is_match(&current)
+                .then(|| parse_scalar(&re.replace_all(&current, new))),
+        }
+    }
+}
+
+/// Replace `old` with `new` in the scalar (or sequence) values of `key`
+/// in every project matching `filters`.
+pub fn run(
+    key: &str,
+    pattern: Pattern,
+    new: &str,
+    filters: &[String],
+    dry_run: bool,
+) -> Result<()> {
+    if key.starts_with('_') {
+        anyhow::bail!("Keys starting with '_' are reserved metadata keys.");
+    }
+
+    let filters: Vec<Filter> = filters
+        .iter()
+        .map(|f| parse_filter(f))
+        .collect::<Result<_>>()?;
+
+    let root = memfs::resolve_workspace_root()?;
+    let dir = memfs::projects_dir(&root);
+    let _lock = memfs::lock_brane(&root)?;
+
+    let mut projects = load_projects(&dir)?;
+    projects.sort_by(|a, b| a.0.cmp(&b.0));
+
+    println!("Replacing values of `{}`", key);
+    if !filters.is_empty() {
+        println!("Scope: projects matching {}", filter_summary(&filters));
+    }
+    println!();
+
+    let mut updated = 0;
+    let mut unmatched = 0;
+    let mut touched: Vec<PathBuf> = Vec::new();
+
+    for (name, mut project) in projects {
+        if !matches_all(&filters, &project) {
+            continue;
+        }
+        let Some(value) = project.get(key) else {
+            continue;
+        };
+
+        let mut replaced = Vec::new();
+        let value = replace_in(value, &pattern, new, &mut replaced);
+
+        if replaced.is_empty() {
+            unmatched += 1;
+            continue;
+        }
+
+        println!("✔ {}  {}", name, replaced.join(", ").dimmed());
+        updated += 1;
+
+        if dry_run {
+            continue;
+        }
+
+        project.insert(key.to_string(), value);
+
+        let path = dir.join(format!("{name}.yaml"));
+        write_project(&path, materialize_project(project, &name)?)?;
+        touched.push(path);
+    }
+
+    if unmatched > 0 {
+        println!(
+            "{}",
+            format!("{} project(s) with `{}` had no matching value.", unmatched, key).dimmed()
+        );
+    }
+
+    if updated == 0 {
+        println!("\nNo projects were updated.");
+    } else if dry_run {
+        println!(
+            "\n{}",
+            format!("Dry run: {} project(s) would be updated, nothing was written.", updated)
+                .dimmed()
+        );
+    } else {
+        println!("\nDone. Updated {} project(s).", updated);
+        git::autocommit(&root, &touched, &format!("replace values of {key}"));
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// Helpers
+// ------------------------------------------------------------
+
+/// Copy of `value` with matching scalars replaced, also inside sequences.
+/// Items a replacement turns into duplicates are dropped (`[wip, in-progress]` → `[in-progress]`).
+/// Each effective change is recorded as `old → new` in `replaced`.
+fn replace_in(value: &Value, pattern: &Pattern, new: &str, replaced: &mut Vec<String>) -> Value {
+    match value {
+        Value::Sequence(items) => {
+            // (item, whether the replacement changed it)
+            let items: Vec<(Value, bool)> = items
+                .iter()
+                .map(|i| {
+                    let before = replaced.len();
+                    (replace_in(i, pattern, new, replaced), replaced.len() > before)
+                })
+                .collect();
+
+            // a replaced item is dropped when it duplicates an untouched item
+            // or an earlier replaced one; duplicates already there stay
+            let duplicate = |i: usize| {
+                let (item, changed) = &items[i];
+                *changed
+                    && items
+                        .iter()
+                        .enumerate()
+                        .any(|(j, (other, c))| j != i && other == item && (!c || j < i))
+            };
+
+            Value::Sequence(
+                (0..items.len())
+                    .filter(|&i| !duplicate(i))
+                    .map(|i| items[i].0.clone())
+                    .collect(),
+            )
+        }
+        Value::Mapping(_) => value.clone(),
+        _ => match pattern.apply(value, new) {
+            Some(updated) if updated != *value => {
+                replaced.push(format!("{} → {}", inline(value), inline(&updated)));
+                updated
+            }
+            _ => value.clone(),
+        },
+    }
+}
+
+fn inline(value: &Value) -> String {
+    render_inline_value(value).unwrap_or_else(|| "[…]".into())
+}
+
+fn filter_summary(filters: &[Filter]) -> String {
+    filters
+        .iter()
+        .map(|f| match &f.op {
+            FilterOp::Has => f.key.clone(),
+            FilterOp::Eq(v) => format!("{} == {}", f.key, v),
+            FilterOp::Ne(v) => format!("{} != {}", f.key, v),
+        })
+        .collect::<Vec<_>>()
+        .join(" and ")
+}
@@ -0,0 +1,193 @@
+use anyhow::Result;
+use colored::Colorize;
+use indexmap::IndexMap;
+use notify_debouncer_full::{new_debouncer, notify::{EventKind, RecursiveMode}};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::core::Project;
+use crate::memfs;
+use crate::commands::show::{
+    load_projects, normalize_fields, render_list_cli, render_project_cli, sort_projects,
+};
+use crate::commands::show_model::{Highlights, ShowContext};
+use crate::utils::resolve::resolve_project;
+
+/// Quiet period before a burst of file events triggers a re-render
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Show options, as passed to `me show`
+pub struct WatchView {
+    pub project: Option<String>,
+    pub sort_key: Option<String>,
+    pub desc: bool,
+    pub only: bool,
+    pub fields: Vec<String>,
+}
+
+/// What moved between two renders
+#[derive(Default)]
+struct Changes {
+    highlights: Highlights,
+    removed: Vec<String>,
+}
+
+pub fn run(mut view: WatchView) -> Result<()> {
+    let root = memfs::resolve_workspace_root()?;
+    let dir = memfs::projects_dir(&root);
+    view.fields = normalize_fields(std::mem::take(&mut view.fields), view.sort_key.as_deref());
+
+    let (tx, rx) = mpsc::channel();
+    let mut debouncer = new_debouncer(DEBOUNCE, None, tx)?;
+    debouncer.watch(&dir, RecursiveMode::NonRecursive)?;
+
+    let mut previous = snapshot(&dir)?;
+    render(&root, &view, &previous, &Changes::default())?;
+
+    for events in rx {
+        let events = events.map_err(|errors| {
+            anyhow::anyhow!("watch failed: {}", errors.first().map(|e| e.to_string()).unwrap_or_default())
+        })?;
+
+        // reads (ours included) and temp files don't count
+        let relevant = events.iter().any(|e| {
+            !matches!(e.kind, EventKind::Access(_))
+                && e.paths.iter().any(|p| p.extension().and_then(|s| s.to_str()) == Some("yaml"))
+        });
+        if !relevant {
+            continue;
+        }
+
+        // a half-written or broken file: keep the last good view
+        let Ok(current) = snapshot(&dir) else {
+            continue;
+        };
+
+        if current == previous {
+            continue;
+        }
+
+        let changes = diff(&previous, &current);
+        render(&root, &view, &current, &changes)?;
+        previous = current;
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------
+// State
+// ------------------------------------------------------------
+
+fn snapshot(dir: &Path) -> Result<IndexMap<String, Project>> {
+    Ok(load_projects(dir)?.into_iter().collect())
+}
+
+fn diff(before: &IndexMap<String, Project>, after: &IndexMap<String, Project>) -> Changes {
+    let mut changes = Changes::default();
+
+    for (name, project) in after {
+        let Some(old) = before.get(name) else {
+            changes.highlights.added.insert(name.clone());
+            continue;
+        };
+
+        let mut keys: Vec<String> = project
+            .iter()
+            .filter(|(k, v)| k.as_str() != "_updated" && old.get(*k) != Some(*v))
+            .map(|(k, _)| k.clone())
+            .collect();
+        keys.extend(old.keys().filter(|k| !project.contains_key(*k)).cloned());
+
+        if !keys.is_empty() {
+            changes.highlights.keys.insert(name.clone(), keys);
+        }
+    }
+
+    changes.removed = before
+        .keys()
+        .filter(|name| !after.contains_key(*name))
+        .cloned()
+        .collect();
+
+    changes
+}
+
+// ------------------------------------------------------------
+// Rendering
+// ------------------------------------------------------------
+
+fn render(
+    root: &Path,
+    view: &WatchView,
+    projects: &IndexMap<String, Project>,
+    changes: &Changes,
+) -> Result<()> {
+    // clear screen, cursor home
+    print!("\x1b[2J\x1b[H");
+
+    println!(
+        "{} {}  {}",
+        "Watching:".dimmed(),
+        root.display(),
+        chrono::Local::now().format("%H:%M:%S").to_string().dimmed()
+    );
+
+    match &view.project {
+        Some(input) => render_single(root, input, changes)?,
+        None => render_list(root, view, projects, changes)?,
+    }
+
+    println!("\n{}", "Ctrl+C to stop.".dimmed());
+    io::stdout().flush()?;
+
+    Ok(())
+}
+
+fn render_list(
+    root: &Path,
+    view: &WatchView,
+    projects: &IndexMap<String, Project>,
+    changes: &Changes,
+) -> Result<()> {
+    let mut rows: Vec<(String, Project)> = projects
+        .iter()
+        .map(|(n, p)| (n.clone(), p.clone()))
+        .collect();
+
+    if let Some(k) = &view.sort_key {
+        sort_projects(&mut rows, k, view.desc);
+        if view.only {
+            rows.retain(|(_, p)| p.contains_key(k));
+        }
+    }
+
+    let ctx = ShowContext {
+        brane_root: root.to_path_buf(),
+        brane_id: memfs::read_brane_id(root).unwrap_or_else(|_| "unknown".into()),
+        sort_key: view.sort_key.clone(),
+        projects: rows,
+        fields: view.fields.clone(),
+    };
+    render_list_cli(&ctx, &changes.highlights)?;
+
+    for name in &changes.removed {
+        println!("{} {}", "-".red().bold(), name.red().strikethrough());
+    }
+
+    Ok(())
+}
+
+fn render_single(root: &Path, input: &str, changes: &Changes) -> Result<()> {
+    let (name, project) = match resolve_project(&memfs::projects_dir(root), input) {
+        Ok(found) => found,
+        Err(e) => {
+            println!("{}", e.to_string().red());
+            return Ok(());
+        }
+    };
+
+    render_project_cli(&name, &project, &changes.highlights)
+}
@@ -0,0 +1,151 @@
+use clap_complete::engine::CompletionCandidate;
+use std::ffi::OsStr;
+use std::path::PathBuf;
+
+use crate::{cache, global, memfs, sweep};
+
+// Value completers for `me completions`. The shell calls back into `me`
+// with `COMPLETE=<shell>`, and clap runs these against the live brane.
+// They must stay quiet: any error simply yields no candidates.
+
+/// Project names, plus ID prefixes when the input looks like one
+/// (for commands that resolve projects by ID: `show`, `edit`, `diff`, ...)
+pub fn projects(current: &OsStr) -> Vec<CompletionCandidate> {
+    project_candidates(current, true)
+}
+
+/// Project names only (for `set`, `unset` and `rm`, which take file names)
+pub fn project_names(current: &OsStr) -> Vec<CompletionCandidate> {
+    project_candidates(current, false)
+}
+
+/// First positional of `set`/`unset`: a project name, or with `--where`/`--all`
+/// (where the positionals shift to KEY VALUE) a key
+pub fn project_or_bulk_key(current: &OsStr) -> Vec<CompletionCandidate> {
+    if bulk_selection() {
+        key_candidates(current, false)
+    } else {
+        project_candidates(current, false)
+    }
+}
+
+/// Second positional of `set`/`unset`: a key, or with `--where`/`--all` the value
+pub fn key_or_bulk_value(current: &OsStr) -> Vec<CompletionCandidate> {
+    if bulk_selection() {
+        Vec::new()
+    } else {
+        key_candidates(current, false)
+    }
+}
+
+/// Every key used in the brane, metadata included (for `--sort` and `-f`)
+pub fn keys(current: &OsStr) -> Vec<CompletionCandidate> {
+    key_candidates(current, true)
+}
+
+/// User keys only (for `set` and `unset`)
+pub fn user_keys(current: &OsStr) -> Vec<CompletionCandidate> {
+    key_candidates(current, false)
+}
+
+/// Brane aliases and short IDs from the global index
+pub fn branes(current: &OsStr) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+    let Ok(index) = global::load_global_index() else {
+        return Vec::new();
+    };
+
+    let mut out = Vec::new();
+
+    for w in &index.workspaces {
+        let short = short(&w.id);
+        let help = w.root.display().to_string();
+
+        if let Some(name) = &w.name {
+            if name.starts_with(current.as_ref()) {
+                out.push(CompletionCandidate::new(name).help(Some(help.clone().into())));
+            }
+        }
+        if short.starts_with(current.as_ref()) {
+            out.push(CompletionCandidate::new(short).help(Some(help.into())));
+        }
+    }
+
+    out
+}
+
+// ---------- internal ----------
+
+fn project_candidates(current: &OsStr, with_ids: bool) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+    let Some(index) = projects_dir().and_then(|d| cache::refresh(&d).ok()) else {
+        return Vec::new();
+    };
+
+    let mut out: Vec<CompletionCandidate> = index
+        .entries
+        .iter()
+        .filter(|(name, _)| name.starts_with(current.as_ref()))
+        .map(|(name, e)| CompletionCandidate::new(name).help(e.id.as_deref().map(short).map(Into::into)))
+        .collect();
+
+    if with_ids && out.is_empty() && !current.is_empty() {
+        out = index
+            .entries
+            .iter()
+            .filter_map(|(name, e)| {
+                let id = e.id.as_deref()?;
+                id.starts_with(current.as_ref())
+                    .then(|| CompletionCandidate::new(short(id)).help(Some(name.clone().into())))
+            })
+            .collect();
+    }
+
+    out
+}
+
+fn key_candidates(current: &OsStr, with_meta: bool) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+    let Some(dir) = projects_dir() else {
+        return Vec::new();
+    };
+    let Ok(counts) = sweep::sweep_keys(&dir) else {
+        return Vec::new();
+    };
+
+    let mut keys: Vec<(String, usize)> = counts
+        .into_iter()
+        .filter(|(k, _)| with_meta || !k.starts_with('_'))
+        .filter(|(k, _)| k.starts_with(current.as_ref()))
+        .collect();
+    keys.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    keys.into_iter()
+        .map(|(k, c)| CompletionCandidate::new(k).help(Some(format!("{c} project(s)").into())))
+        .collect()
+}
+
+fn projects_dir() -> Option<PathBuf> {
+    // the `--brane` flag isn't parsed yet while completing; honour the env var
+    if let Ok(target) = std::env::var("MEMBRANE_BRANE") {
+        if !target.trim().is_empty() {
+            memfs::set_brane_override(target);
+        }
+    }
+
+    let root = memfs::resolve_workspace_root().ok()?;
+    Some(memfs::projects_dir(&root))
+}
+
+fn short(id: &str) -> String {
+    id.chars().take(8).collect()
+}
+
+/// Whether the command line being completed selects projects in bulk.
+/// Completers only see the current word, so look at the full invocation.
+fn bulk_selection() -> bool {
+    std::env::args_os().any(|a| {
+        let a = a.to_string_lossy();
+        a == "--all" || a == "--where" || a.starts_with("--where=")
+    })
+}
@@ -0,0 +1,156 @@
+use anyhow::{Context, Result};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `.membrane/config.yaml`
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BraneConfig {
+    #[serde(default)]
+    pub git: GitConfig,
+
+    /// Keys whose values may link to other projects (`me export-vault`, `me site build`); empty means all
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<String>,
+
+    /// Unknown keys (e.g. `version`) are carried through untouched
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GitConfig {
+    /// Commit touched files after every mutating command
+    #[serde(default)]
+    pub autocommit: bool,
+
+    /// Number of changes to collect before committing (1 = every change)
+    #[serde(default = "default_batch")]
+    pub batch: usize,
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            autocommit: false,
+            batch: default_batch(),
+        }
+    }
+}
+
+fn default_batch() -> usize {
+    1
+}
+
+pub fn config_path(root: &Path) -> PathBuf {
+    root.join(".membrane").join("config.yaml")
+}
+
+pub fn load_config(root: &Path) -> Result<BraneConfig> {
+    let path = config_path(root);
+
+    if !path.exists() {
+        return Ok(BraneConfig::default());
+    }
+
+    let content = fs::read_to_string(&path)?;
+
+    if content.trim().is_empty() {
+        return Ok(BraneConfig::default());
+    }
+
+    serde_yaml::from_str(&content)
+        .with_context(|| format!("Invalid brane config: {}", path.display()))
+}
+
+// ------------------------------------------------------------
+// GLOBAL CONFIG (<global home>/settings.yaml)
+// ------------------------------------------------------------
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GlobalConfig {
+    #[serde(default)]
+    pub discovery: DiscoveryConfig,
+
+    /// Let the nearest `.membrane` from cwd win over the active brane
+    #[serde(default)]
+    pub prefer_local: bool,
+
+    #[serde(flatten)]
+    pub extra: IndexMap<String, Value>,
+}
+
+/// Where and how `me brane --scan` looks for `.membrane/id` files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoveryConfig {
+    /// Directories to search (default: home directory)
+    #[serde(default)]
+    pub roots: Vec<PathBuf>,
+
+    #[serde(default = "default_max_depth")]
+    pub max_depth: usize,
+
+    /// Glob patterns for directories to skip
+    #[serde(default = "default_ignore")]
+    pub ignore: Vec<String>,
+
+    /// Skip anything excluded by `.gitignore` files
+    #[serde(default = "default_true")]
+    pub gitignore: bool,
+
+    /// Worker threads for the scan (0 = pick automatically)
+    #[serde(default)]
+    pub threads: usize,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            roots: Vec::new(),
+            max_depth: default_max_depth(),
+            ignore: default_ignore(),
+            gitignore: true,
+            threads: 0,
+        }
+    }
+}
+
+fn default_max_depth() -> usize {
+    6
+}
+
+fn default_ignore() -> Vec<String> {
+    ["node_modules", "target", ".git", ".cache", ".cargo", ".rustup", ".npm"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// <global home>/settings.yaml
+/// (kept apart from `config.yaml`, which belongs to a brane living in `~`)
+pub fn global_config_path() -> Result<PathBuf> {
+    Ok(crate::global::global_home()?.join("settings.yaml"))
+}
+
+pub fn load_global_config() -> Result<GlobalConfig> {
+    let path = global_config_path()?;
+
+    if !path.exists() {
+        return Ok(GlobalConfig::default());
+    }
+
+    let content = fs::read_to_string(&path)?;
+
+    if content.trim().is_empty() {
+        return Ok(GlobalConfig::default());
+    }
+
+    serde_yaml::from_str(&content)
+        .with_context(|| format!("Invalid global config: {}", path.display()))
+}
@@ -0,0 +1,141 @@
+use serde::Serialize;
+use serde_yaml::Value;
+
+use crate::core::Project;
+use crate::utils::project_writer::RESERVED_KEYS;
+
+/// One difference between two projects, addressed by a path such as `meta.level` or `tags[2]`
+#[derive(Debug, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum Change {
+    Added { path: String, new: Value },
+    Removed { path: String, old: Value },
+    Changed { path: String, old: Value, new: Value },
+    /// Same keys in a different order (only reported with `--order`)
+    Reordered { path: String, old: Vec<String>, new: Vec<String> },
+}
+
+/// Key-aware diff of two projects.
+/// Metadata keys are skipped; key order only counts when `order` is set.
+pub fn diff_projects(a: &Project, b: &Project, order: bool) -> Vec<Change> {
+    let entries = |p: &'_ Project| -> Vec<(String, Value)> {
+        p.iter()
+            .filter(|(k, _)| !RESERVED_KEYS.contains(&k.as_str()))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    };
+
+    let mut changes = Vec::new();
+    diff_entries("", &entries(a), &entries(b), order, &mut changes);
+    changes
+}
+
+// ------------------------------------------------------------
+// Internal
+// ------------------------------------------------------------
+
+type Entries = [(String, Value)];
+
+fn diff_entries(prefix: &str, a: &Entries, b: &Entries, order: bool, out: &mut Vec<Change>) {
+    let lookup = |entries: &Entries, key: &str| {
+        entries.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+    };
+
+    for (key, old) in a {
+        let path = join(prefix, key);
+        match lookup(b, key) {
+            None => out.push(Change::Removed { path, old: old.clone() }),
+            Some(new) => diff_values(&path, old, &new, order, out),
+        }
+    }
+
+    for (key, new) in b {
+        if lookup(a, key).is_none() {
+            out.push(Change::Added {
+                path: join(prefix, key),
+                new: new.clone(),
+            });
+        }
+    }
+
+    if order {
+        // compare the order of the keys both sides have
+        let shared = |x: &Entries, y: &Entries| -> Vec<String> {
+            x.iter()
+                .map(|(k, _)| k.clone())
+                .filter(|k| y.iter().any(|(other, _)| other == k))
+                .collect()
+        };
+
+        let (old, new) = (shared(a, b), shared(b, a));
+        if old != new {
+            out.push(Change::Reordered {
+                path: if prefix.is_empty() { ".".into() } else { prefix.into() },
+                old,
+                new,
+            });
+        }
+    }
+}
+
+fn diff_values(path: &str, old: &Value, new: &Value, order: bool, out: &mut Vec<Change>) {
+    match (old, new) {
+        (Value::Mapping(a), Value::Mapping(b)) => {
+            let entries = |m: &'_ serde_yaml::Mapping| -> Vec<(String, Value)> {
+                m.iter().map(|(k, v)| (key_label(k), v.clone())).collect()
+            };
+            diff_entries(path, &entries(a), &entries(b), order, out);
+        }
+        (Value::Sequence(a), Value::Sequence(b)) if a != b => {
+            if a.len() == b.len() {
+                for (i, (x, y)) in a.iter().zip(b).enumerate() {
+                    diff_values(&format!("{path}[{i}]"), x, y, order, out);
+                }
+                return;
+            }
+
+            // lengths differ: report items gained and lost
+            let mut remaining = b.clone();
+            for item in a {
+                match remaining.iter().position(|r| r == item) {
+                    Some(i) => {
+                        remaining.remove(i);
+                    }
+                    None => out.push(Change::Removed {
+                        path: format!("{path}[]"),
+                        old: item.clone(),
+                    }),
+                }
+            }
+            for item in remaining {
+                out.push(Change::Added {
+                    path: format!("{path}[]"),
+                    new: item,
+                });
+            }
+        }
+        _ if old != new => out.push(Change::Changed {
+            path: path.to_string(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+fn key_label(key: &Value) -> String {
+    match key {
+        Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default(),
+    }
+}
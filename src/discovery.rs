@@ -0,0 +1,91 @@
+use anyhow::Result;
+use ignore::overrides::OverrideBuilder;
+use ignore::{WalkBuilder, WalkState};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::config::DiscoveryConfig;
+
+/// Search the configured roots for `.membrane/id` files.
+/// Returns `(id, root)` pairs, sorted by root.
+pub fn discover_branes(cfg: &DiscoveryConfig) -> Result<Vec<(String, PathBuf)>> {
+    let roots = if cfg.roots.is_empty() {
+        vec![dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Home directory not found"))?]
+    } else {
+        cfg.roots.iter().map(|r| expand_home(r)).collect()
+    };
+
+    let found = Mutex::new(Vec::new());
+
+    for root in roots.iter().filter(|r| r.is_dir()) {
+        // `!pattern` in an override excludes matching paths
+        let mut overrides = OverrideBuilder::new(root);
+        for glob in &cfg.ignore {
+            overrides.add(&format!("!{glob}"))?;
+        }
+
+        let walker = WalkBuilder::new(root)
+            .max_depth(Some(cfg.max_depth))
+            .follow_links(false)
+            // .membrane is a hidden directory; hidden files must be visited
+            .hidden(false)
+            .git_ignore(cfg.gitignore)
+            .git_global(cfg.gitignore)
+            .git_exclude(cfg.gitignore)
+            .require_git(false)
+            .ignore(false)
+            .parents(false)
+            .overrides(overrides.build()?)
+            .threads(cfg.threads)
+            .build_parallel();
+
+        walker.run(|| {
+            Box::new(|entry| {
+                let Ok(entry) = entry else {
+                    return WalkState::Continue;
+                };
+
+                if let Some(hit) = brane_at(entry.path()) {
+                    found.lock().unwrap().push(hit);
+                }
+
+                WalkState::Continue
+            })
+        });
+    }
+
+    let mut found = found.into_inner().unwrap();
+    found.sort_by(|a, b| a.1.cmp(&b.1));
+    found.dedup();
+    Ok(found)
+}
+
+/// `(id, root)` when `path` is a `.membrane/id` file with a non-empty ID
+fn brane_at(path: &Path) -> Option<(String, PathBuf)> {
+    if path.file_name()? != "id" {
+        return None;
+    }
+
+    let membrane_dir = path.parent()?;
+    if !membrane_dir.ends_with(".membrane") {
+        return None;
+    }
+
+    let id = fs::read_to_string(path).ok()?;
+    let id = id.trim();
+
+    // HARD GUARD: never register empty IDs
+    if id.is_empty() {
+        return None;
+    }
+
+    Some((id.to_string(), membrane_dir.parent()?.to_path_buf()))
+}
+
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
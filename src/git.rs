@@ -0,0 +1,166 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{config, memfs};
+use crate::utils::fsio::write_atomic;
+
+/// Changes staged but not yet committed when `git.batch` > 1
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Pending {
+    messages: Vec<String>,
+    paths: Vec<PathBuf>,
+}
+
+fn pending_path(root: &Path) -> PathBuf {
+    root.join(".membrane").join("git_pending.yaml")
+}
+
+// ---------- public API ----------
+
+/// Record a mutation of `paths` for `git.autocommit`.
+/// Never fails the calling command: git problems are reported as warnings.
+pub fn autocommit(root: &Path, paths: &[PathBuf], message: &str) {
+    if let Err(e) = try_autocommit(root, paths, message) {
+        eprintln!("{} git autocommit failed: {}", "⚠️".yellow(), e);
+    }
+}
+
+/// Commit everything collected by a batched autocommit
+pub fn flush(root: &Path) -> Result<usize> {
+    if !in_repo(root) {
+        anyhow::bail!("Brane at {} is not inside a git repository", root.display());
+    }
+
+    let pending = load_pending(root)?;
+    let count = pending.messages.len();
+
+    if count > 0 {
+        commit_pending(root, pending)?;
+    }
+
+    Ok(count)
+}
+
+/// Contents of `path` (relative to `root`) as of git revision `rev`
+pub fn show_at(root: &Path, rev: &str, path: &Path) -> Result<String> {
+    if !in_repo(root) {
+        anyhow::bail!("Brane at {} is not inside a git repository", root.display());
+    }
+
+    let spec = format!("{}:./{}", rev, path.display());
+    let out = Command::new("git").arg("-C").arg(root).args(["show", &spec]).output()?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "Cannot read {} at `{}`: {}",
+            path.display(),
+            rev,
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&out.stdout).into_owned())
+}
+
+// ---------- internal ----------
+
+fn try_autocommit(root: &Path, paths: &[PathBuf], message: &str) -> Result<()> {
+    let cfg = config::load_config(root)?.git;
+
+    if !cfg.autocommit || !in_repo(root) {
+        return Ok(());
+    }
+
+    // keep our lock/pending/cache files out of `git status`, and commit the ignore file itself
+    let mut paths = paths.to_vec();
+    paths.push(memfs::ensure_gitignore(root)?);
+
+    let mut pending = load_pending(root)?;
+    pending.messages.push(message.to_string());
+    for p in &paths {
+        if !pending.paths.contains(p) {
+            pending.paths.push(p.clone());
+        }
+    }
+
+    if pending.messages.len() >= cfg.batch.max(1) {
+        commit_pending(root, pending)
+    } else {
+        git(root, &stage_args(&paths))?;
+        save_pending(root, &pending)
+    }
+}
+
+fn commit_pending(root: &Path, pending: Pending) -> Result<()> {
+    git(root, &stage_args(&pending.paths))?;
+
+    let message = match pending.messages.as_slice() {
+        [single] => single.clone(),
+        many => format!(
+            "membrane: {} changes\n\n{}",
+            many.len(),
+            many.iter().map(|m| format!("- {m}")).collect::<Vec<_>>().join("\n")
+        ),
+    };
+
+    // only commit the touched paths, leaving anything else the user staged alone
+    let mut args: Vec<String> = vec!["commit".into(), "-q".into(), "-m".into(), message, "--".into()];
+    args.extend(pending.paths.iter().map(|p| p.display().to_string()));
+    git(root, &args)?;
+
+    let path = pending_path(root);
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+
+    Ok(())
+}
+
+fn stage_args(paths: &[PathBuf]) -> Vec<String> {
+    let mut args: Vec<String> = vec!["add".into(), "-A".into(), "--".into()];
+    args.extend(paths.iter().map(|p| p.display().to_string()));
+    args
+}
+
+fn in_repo(root: &Path) -> bool {
+    Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+fn git(root: &Path, args: &[String]) -> Result<()> {
+    let out = Command::new("git").arg("-C").arg(root).args(args).output()?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "`git {}` failed: {}",
+            args.first().map(String::as_str).unwrap_or(""),
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+
+    Ok(())
+}
+
+fn load_pending(root: &Path) -> Result<Pending> {
+    let path = pending_path(root);
+
+    if !path.exists() {
+        return Ok(Pending::default());
+    }
+
+    Ok(serde_yaml::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn save_pending(root: &Path, pending: &Pending) -> Result<()> {
+    write_atomic(&pending_path(root), serde_yaml::to_string(pending)?)?;
+    Ok(())
+}
@@ -1,84 +1,298 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::utils::fsio::{lock_exclusive, write_atomic};
 use crate::utils::time::now_iso;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Number of rotating backups kept next to `global.yaml`
+pub const BACKUP_COUNT: usize = 5;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct GlobalIndex {
+    #[serde(default)]
     pub active: Option<String>,
+    #[serde(default)]
     pub workspaces: Vec<WorkspaceEntry>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WorkspaceEntry {
     pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     pub root: PathBuf,
     pub last_seen: String,
 }
 
-/// ~/.membrane/global.yaml
-pub fn global_index_path() -> PathBuf {
-    dirs::home_dir()
-        .expect("Home directory not found")
-        .join(".membrane")
-        .join("global.yaml")
+impl WorkspaceEntry {
+    /// Alias if set, otherwise the short ID
+    pub fn label(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| self.id.chars().take(8).collect())
+    }
 }
 
-pub fn load_global_index() -> GlobalIndex {
-    let path = global_index_path();
+/// Directory holding global state (index, backups, settings).
+/// Priority:
+/// 1. `$MEMBRANE_HOME`
+/// 2. `~/.membrane`, when it already holds a global index
+/// 3. `$XDG_CONFIG_HOME/membrane`
+/// 4. `~/.membrane`
+/// 5. Platform config directory (`membrane/`)
+pub fn global_home() -> Result<PathBuf> {
+    if let Some(dir) = env_dir("MEMBRANE_HOME") {
+        return Ok(dir);
+    }
+
+    let legacy = dirs::home_dir().map(|h| h.join(".membrane"));
+
+    if let Some(dir) = &legacy {
+        if dir.join("global.yaml").exists() {
+            return Ok(dir.clone());
+        }
+    }
+
+    if let Some(xdg) = env_dir("XDG_CONFIG_HOME") {
+        return Ok(xdg.join("membrane"));
+    }
+
+    legacy
+        .or_else(|| dirs::config_dir().map(|c| c.join("membrane")))
+        .ok_or_else(|| anyhow::anyhow!(
+            "Cannot locate global Membrane state: no home directory found. Set MEMBRANE_HOME."
+        ))
+}
+
+fn env_dir(var: &str) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+/// <global home>/global.yaml
+pub fn global_index_path() -> Result<PathBuf> {
+    Ok(global_home()?.join("global.yaml"))
+}
+
+/// <global home>/backups/global.<n>.yaml (1 = newest)
+pub fn backup_path(n: usize) -> Result<PathBuf> {
+    Ok(global_home()?
+        .join("backups")
+        .join(format!("global.{n}.yaml")))
+}
+
+/// Load the global index.
+/// A missing file is an empty index; an unreadable or corrupt file is an error.
+pub fn load_global_index() -> Result<GlobalIndex> {
+    let path = global_index_path()?;
 
     if !path.exists() {
-        return GlobalIndex {
-            active: None,
-            workspaces: Vec::new(),
-        };
+        return Ok(GlobalIndex::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read global index {}", path.display()))?;
+
+    parse_global_index(&content).with_context(|| {
+        format!(
+            "Global index {} is corrupt. Run `me brane repair` to restore it.",
+            path.display()
+        )
+    })
+}
+
+pub fn parse_global_index(content: &str) -> Result<GlobalIndex> {
+    if content.trim().is_empty() {
+        return Ok(GlobalIndex::default());
     }
 
-    serde_yaml::from_str(&fs::read_to_string(path).unwrap())
-        .unwrap_or(GlobalIndex {
-            active: None,
-            workspaces: Vec::new(),
-        })
+    Ok(serde_yaml::from_str(content)?)
 }
 
+/// Save the global index and rotate the saved content into the backups,
+/// so `backups/global.1.yaml` is always the last good state.
+/// Refuses to overwrite a file that does not parse: use `replace_global_index`.
 pub fn save_global_index(index: &GlobalIndex) -> Result<()> {
-    let path = global_index_path();
+    let path = global_index_path()?;
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
-    fs::write(path, serde_yaml::to_string(index)?)?;
+
+    if path.exists() {
+        let current = fs::read_to_string(&path)?;
+
+        if parse_global_index(&current).is_err() {
+            anyhow::bail!(
+                "Refusing to overwrite unparseable global index {}. Run `me brane repair`.",
+                path.display()
+            );
+        }
+    }
+
+    let content = serde_yaml::to_string(index)?;
+    write_atomic(&path, &content)?;
+    rotate_backups(&content)?;
     Ok(())
 }
 
-/// Register or refresh a workspace
-pub fn register_workspace(id: &str, root: &Path) -> Result<()> {
-    let mut index = load_global_index();
-    let now = now_iso();
+/// Replace the global index wholesale, moving a corrupt file aside first
+pub fn replace_global_index(index: &GlobalIndex) -> Result<Option<PathBuf>> {
+    let path = global_index_path()?;
+    let mut moved = None;
+
+    if path.exists() {
+        let current = fs::read_to_string(&path)?;
+
+        if parse_global_index(&current).is_err() {
+            let aside = path.with_file_name(format!(
+                "global.corrupt-{}.yaml",
+                chrono::Utc::now().format("%Y%m%dT%H%M%S")
+            ));
+            fs::rename(&path, &aside)?;
+            moved = Some(aside);
+        }
+    }
+
+    save_global_index(index)?;
+    Ok(moved)
+}
+
+fn rotate_backups(saved: &str) -> Result<()> {
+    let newest = backup_path(1)?;
 
-    if let Some(w) = index.workspaces.iter_mut().find(|w| w.id == id) {
-        w.last_seen = now;
-        w.root = root.to_path_buf();
-    } else {
-        index.workspaces.push(WorkspaceEntry {
-            id: id.to_string(),
-            root: root.to_path_buf(),
-            last_seen: now,
-        });
+    // don't churn the backups with identical copies
+    if fs::read_to_string(&newest).ok().as_deref() == Some(saved) {
+        return Ok(());
+    }
+
+    if let Some(parent) = newest.parent() {
+        fs::create_dir_all(parent)?;
     }
 
-    if index.active.is_none() {
-        index.active = Some(id.to_string());
+    for n in (1..BACKUP_COUNT).rev() {
+        let from = backup_path(n)?;
+        if from.exists() {
+            fs::rename(&from, backup_path(n + 1)?)?;
+        }
+    }
+
+    write_atomic(&newest, saved)
+}
+
+/// <global home>/global.lock
+pub fn global_lock_path() -> Result<PathBuf> {
+    Ok(global_home()?.join("global.lock"))
+}
+
+/// Load, mutate and save the global index under an exclusive lock
+pub fn update_global_index<T>(
+    f: impl FnOnce(&mut GlobalIndex) -> Result<T>,
+) -> Result<T> {
+    let _lock = lock_exclusive(&global_lock_path()?)?;
+
+    let mut index = load_global_index()?;
+    let out = f(&mut index)?;
+    save_global_index(&index)?;
+
+    Ok(out)
+}
+
+impl GlobalIndex {
+    /// Register or refresh a workspace in memory
+    pub fn upsert(&mut self, id: &str, root: &Path) {
+        let now = now_iso();
+
+        if let Some(w) = self.workspaces.iter_mut().find(|w| w.id == id) {
+            w.last_seen = now;
+            w.root = root.to_path_buf();
+        } else {
+            self.workspaces.push(WorkspaceEntry {
+                id: id.to_string(),
+                name: None,
+                root: root.to_path_buf(),
+                last_seen: now,
+            });
+        }
+
+        if self.active.is_none() {
+            self.active = Some(id.to_string());
+        }
     }
 
-    save_global_index(&index)
+    /// Find a workspace by exact alias, then by leading ID prefix
+    pub fn find(&self, target: &str) -> Result<&WorkspaceEntry> {
+        if let Some(w) = self
+            .workspaces
+            .iter()
+            .find(|w| w.name.as_deref() == Some(target))
+        {
+            return Ok(w);
+        }
+
+        let matches: Vec<_> = self
+            .workspaces
+            .iter()
+            .filter(|w| w.id.starts_with(target))
+            .collect();
+
+        match matches.len() {
+            0 => anyhow::bail!("No workspace matches '{}'", target),
+            1 => Ok(matches[0]),
+            _ => {
+                let ids: Vec<_> = matches.iter().map(|w| w.label()).collect();
+                anyhow::bail!("Ambiguous prefix '{}': {:?}", target, ids)
+            }
+        }
+    }
+
+    /// Give a workspace an alias, keeping aliases unique
+    pub fn set_name(&mut self, id: &str, name: &str) -> Result<()> {
+        validate_alias(name)?;
+
+        if let Some(other) = self
+            .workspaces
+            .iter()
+            .find(|w| w.name.as_deref() == Some(name) && w.id != id)
+        {
+            anyhow::bail!(
+                "Alias '{}' is already used by brane {}",
+                name,
+                other.id.chars().take(8).collect::<String>()
+            );
+        }
+
+        let ws = self
+            .workspaces
+            .iter_mut()
+            .find(|w| w.id == id)
+            .ok_or_else(|| anyhow::anyhow!("Workspace {} is not registered", id))?;
+
+        ws.name = Some(name.to_string());
+        Ok(())
+    }
+}
+
+fn validate_alias(name: &str) -> Result<()> {
+    if name.is_empty() || name.chars().any(|c| c.is_whitespace() || c == '/') {
+        anyhow::bail!("Invalid alias '{}': use a single word without '/'", name);
+    }
+    Ok(())
+}
+
+/// Register or refresh a workspace
+pub fn register_workspace(id: &str, root: &Path) -> Result<()> {
+    update_global_index(|index| {
+        index.upsert(id, root);
+        Ok(())
+    })
 }
 
 /// Resolve active workspace root
-#[allow(dead_code)]
 pub fn active_workspace_root() -> Result<PathBuf> {
-    let index = load_global_index();
+    let index = load_global_index()?;
 
     let active_id = index
         .active
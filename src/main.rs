@@ -1,13 +1,22 @@
-use clap::{Parser, Subcommand};
+use clap::{Args, CommandFactory, Parser, Subcommand};
 use clap::builder::Styles;
+use clap_complete::engine::ArgValueCompleter;
+use clap_complete::env::CompleteEnv;
 use anyhow::Result;
 
 mod core;
 mod memfs;
 mod sweep;
+mod sync;
+mod diff;
 mod commands;
 mod utils;
 mod global;
+mod config;
+mod discovery;
+mod completion;
+mod cache;
+mod git;
 
 #[derive(Parser)]
 #[command(
@@ -16,6 +25,15 @@ mod global;
     styles = membrane_styles()
 )]
 struct Cli {
+    /// Run against this brane (path, alias or ID prefix) instead of the active one
+    #[arg(
+        long,
+        global = true,
+        env = "MEMBRANE_BRANE",
+        add = ArgValueCompleter::new(completion::branes)
+    )]
+    brane: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -29,24 +47,128 @@ enum KeysAction {
         #[arg(long)]
         project: Option<String>,
     },
+    /// List the distinct values of a key and the projects using them
+    Values {
+        key: String,
+
+        /// Flag near-duplicate values (`in progress` / `in-progress` / `WIP`)
+        #[arg(long)]
+        similar: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ValuesAction {
+    /// Replace a value of KEY across projects (also inside lists)
+    Replace {
+        key: String,
+        old: String,
+        new: String,
+
+        /// Treat OLD as a regex; NEW may use `$1`-style capture groups
+        #[arg(long)]
+        regex: bool,
+
+        /// Only projects matching `key == value`, `key != value` or `key` (repeatable)
+        #[arg(long = "where")]
+        filters: Vec<String>,
+
+        /// Show what would change without writing
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum BraneAction {
+    Name {
+        #[arg(add = ArgValueCompleter::new(completion::branes))]
+        target: String,
+        alias: String,
+    },
+    Repair {
+        #[arg(long)]
+        scan: bool,
+    },
+}
+
+// Apply `set`/`unset` to many projects at once
+#[derive(Args)]
+struct BulkArgs {
+    /// Only projects matching `key == value`, `key != value` or `key` (repeatable)
+    #[arg(long = "where")]
+    filters: Vec<String>,
+
+    /// Every project in the brane
+    #[arg(long, conflicts_with = "filters")]
+    all: bool,
+
+    /// List the projects that would change, without writing
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Don't ask for confirmation on large changes
+    #[arg(short, long)]
+    yes: bool,
+}
+
+impl BulkArgs {
+    fn active(&self) -> bool {
+        self.all || !self.filters.is_empty()
+    }
+
+    fn split(self) -> (commands::bulk::Selection, commands::bulk::BulkOptions) {
+        (
+            commands::bulk::Selection { filters: self.filters, all: self.all },
+            commands::bulk::BulkOptions { dry_run: self.dry_run, yes: self.yes },
+        )
+    }
+}
+
+#[derive(Subcommand)]
+enum SiteAction {
+    Build {
+        /// Output directory (created if missing)
+        outdir: String,
+    },
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    Init,
+    Init {
+        #[arg(long)]
+        name: Option<String>,
+    },
     Prune,
     Register,
-    Brane,
+    Brane {
+        #[command(subcommand)]
+        action: Option<BraneAction>,
+
+        /// Search the filesystem for branes before listing
+        #[arg(long)]
+        scan: bool,
+
+        /// Directory to search (repeatable; overrides the configured roots)
+        #[arg(long = "root", requires = "scan")]
+        roots: Vec<std::path::PathBuf>,
+
+        /// Maximum search depth (overrides the configured depth)
+        #[arg(long, requires = "scan")]
+        depth: Option<usize>,
+    },
     Checkout {
+        #[arg(add = ArgValueCompleter::new(completion::branes))]
         target: String,
     },
     Add {
         name: String,
     },
     Show {
+        #[arg(add = ArgValueCompleter::new(completion::projects))]
         project: Option<String>,
 
-        #[arg(long)]
+        #[arg(long, add = ArgValueCompleter::new(completion::keys))]
         sort: Option<String>,
 
         #[arg(long)]
@@ -58,28 +180,94 @@ enum Commands {
         #[arg(long)]
         only: bool,
 
-        #[arg(short = 'f', long = "fields", value_delimiter = ',')]
+        #[arg(
+            short = 'f',
+            long = "fields",
+            value_delimiter = ',',
+            add = ArgValueCompleter::new(completion::keys)
+        )]
+        fields: Vec<String>,
+
+        /// Render --printed through .membrane/templates/export/<TEMPLATE>
+        #[arg(long, requires = "printed")]
+        template: Option<String>,
+    },
+
+    Watch {
+        #[arg(add = ArgValueCompleter::new(completion::projects))]
+        project: Option<String>,
+
+        #[arg(long, add = ArgValueCompleter::new(completion::keys))]
+        sort: Option<String>,
+
+        #[arg(long)]
+        desc: bool,
+
+        #[arg(long)]
+        only: bool,
+
+        #[arg(
+            short = 'f',
+            long = "fields",
+            value_delimiter = ',',
+            add = ArgValueCompleter::new(completion::keys)
+        )]
         fields: Vec<String>,
     },
 
     Set {
-        project: String,
-        key: String,
+        /// Project (with --where/--all: the key)
+        #[arg(add = ArgValueCompleter::new(completion::project_or_bulk_key))]
+        project: Option<String>,
+        /// Key (with --where/--all: the value)
+        #[arg(add = ArgValueCompleter::new(completion::key_or_bulk_value))]
+        key: Option<String>,
         value: Option<String>,
+
+        /// Key to set on every selected project (with --where/--all; same as the positional KEY)
+        #[arg(long = "key", add = ArgValueCompleter::new(completion::user_keys))]
+        key_flag: Option<String>,
+
+        #[command(flatten)]
+        bulk: BulkArgs,
     },
-    Unset {
+    Edit {
+        #[arg(add = ArgValueCompleter::new(completion::projects))]
         project: String,
-        key: String,
+    },
+    Unset {
+        /// Project (with --where/--all: the key)
+        #[arg(add = ArgValueCompleter::new(completion::project_or_bulk_key))]
+        project: Option<String>,
+        #[arg(add = ArgValueCompleter::new(completion::key_or_bulk_value))]
+        key: Option<String>,
+
+        /// Key to remove from every selected project (with --where/--all; same as the positional KEY)
+        #[arg(long = "key", add = ArgValueCompleter::new(completion::user_keys))]
+        key_flag: Option<String>,
+
+        #[command(flatten)]
+        bulk: BulkArgs,
     },
     Rm {
+        #[arg(add = ArgValueCompleter::new(completion::project_names))]
         project: String,
     },
+    #[command(args_conflicts_with_subcommands = true)]
     Keys {
         #[command(subcommand)]
         action: Option<KeysAction>,
 
         #[arg(long)]
         similar: bool,
+
+        /// Report the YAML types each key holds and flag keys whose types disagree
+        #[arg(long, conflicts_with = "similar")]
+        types: bool,
+    },
+    Values {
+        #[command(subcommand)]
+        action: ValuesAction,
     },
     Push {
         file: Option<String>,
@@ -87,6 +275,71 @@ enum Commands {
         #[arg(long)]
         as_name: Option<String>,
     },
+    ImportMd {
+        /// Markdown file, or a directory to search recursively
+        target: String,
+
+        /// Key that receives the markdown body
+        #[arg(long, default_value = "notes")]
+        body_key: String,
+    },
+    ExportVault {
+        /// Output directory (created if missing)
+        dir: String,
+    },
+    Site {
+        #[command(subcommand)]
+        action: SiteAction,
+    },
+    Diff {
+        /// Project name, ID prefix, <brane>:<project>, <project>@<git rev> or a YAML file
+        #[arg(add = ArgValueCompleter::new(completion::projects))]
+        a: String,
+        #[arg(add = ArgValueCompleter::new(completion::projects))]
+        b: String,
+
+        /// Output format: text or json
+        #[arg(long, default_value = "text")]
+        format: String,
+
+        /// Also report differences in key order
+        #[arg(long)]
+        order: bool,
+    },
+    Sync {
+        other: String,
+    },
+    Commit,
+    Reindex,
+    Completions {
+        shell: String,
+    },
+    Tui {
+        #[arg(long, add = ArgValueCompleter::new(completion::keys))]
+        sort: Option<String>,
+
+        #[arg(long)]
+        desc: bool,
+    },
+    Doctor {
+        #[arg(long)]
+        fix: bool,
+    },
+    Serve {
+        /// Port to listen on (bound to 127.0.0.1 only)
+        #[arg(long, default_value_t = 4870)]
+        port: u16,
+    },
+    Rpc,
+    Resolve {
+        project: Option<String>,
+
+        #[arg(long, conflicts_with = "theirs")]
+        ours: bool,
+
+        #[arg(long)]
+        theirs: bool,
+    },
 }
 
 fn membrane_styles() -> Styles {
@@ -110,6 +363,9 @@ fn main() -> Result<()> {
     use std::env;
     use crate::utils::banner::print_wordmark;
 
+    // Shell completion callback (`COMPLETE=<shell> me ...`): answers and exits
+    CompleteEnv::with_factory(Cli::command).complete();
+
     let args: Vec<String> = env::args().collect();
 
     // Show wordmark only on bare `me`
@@ -119,17 +375,29 @@ fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    if let Some(brane) = cli.brane.filter(|b| !b.trim().is_empty()) {
+        memfs::set_brane_override(brane);
+    }
+
     match cli.command {
-        Commands::Init =>
-            memfs::init_membrane(),
+        Commands::Init { name } =>
+            memfs::init_membrane(name.as_deref()),
 
         Commands::Prune =>
             commands::prune::run(),
 
         Commands::Register => commands::register::run(),
         
-        Commands::Brane =>
-            commands::brane::run(),
+        Commands::Brane { action, scan, roots, depth } => {
+            match action {
+                Some(BraneAction::Name { target, alias }) =>
+                    commands::brane_name::run(&target, &alias),
+                Some(BraneAction::Repair { scan }) =>
+                    commands::brane_repair::run(scan),
+                None =>
+                    commands::brane::run(scan, roots, depth),
+            }
+        }
 
         Commands::Checkout { target } =>
             commands::checkout::run(&target),
@@ -144,6 +412,7 @@ fn main() -> Result<()> {
             printed,
             only,
             fields,
+            template,
         } =>
             commands::show::run(
                 project.as_deref(),
@@ -152,18 +421,77 @@ fn main() -> Result<()> {
                 printed,
                 only,
                 fields,
+                template.as_deref(),
             ),
 
-        Commands::Set { project, key, value } =>
-            commands::set::run(&project, &key, value.as_deref()),
+        Commands::Watch {
+            project,
+            sort,
+            desc,
+            only,
+            fields,
+        } =>
+            commands::watch::run(commands::watch::WatchView {
+                project,
+                sort_key: sort,
+                desc,
+                only,
+                fields,
+            }),
+
+        Commands::Set { project, key, value, key_flag, bulk } => {
+            if bulk.active() {
+                // positionals shift left: KEY VALUE, or just VALUE after --key
+                let (key, value) = match (key_flag, project, key, value) {
+                    (Some(k), v, None, None) | (None, Some(k), v, None) => (k, v),
+                    _ => anyhow::bail!("With --where/--all, pass only KEY and VALUE"),
+                };
+                let value = match value {
+                    Some(v) => utils::parse::parse_scalar(&v),
+                    None => serde_yaml::Value::String(utils::input::read_multiline(
+                        &format!("✏️  Enter value for key `{}`:", key),
+                    )?),
+                };
+                let (selection, opts) = bulk.split();
+                commands::bulk::run(commands::bulk::BulkOp::Set { key, value }, selection, opts)
+            } else {
+                let (Some(project), Some(key)) = (project, key) else {
+                    anyhow::bail!("Usage: me set <PROJECT> <KEY> [VALUE]");
+                };
+                if key_flag.is_some() || bulk.dry_run || bulk.yes {
+                    anyhow::bail!("--key, --dry-run and --yes only apply with --where or --all");
+                }
+                commands::set::run(&project, &key, value.as_deref())
+            }
+        }
+
+        Commands::Edit { project } =>
+            commands::edit::run(&project),
 
-        Commands::Unset { project, key } =>
-            commands::unset::run(&project, &key),
+        Commands::Unset { project, key, key_flag, bulk } => {
+            if bulk.active() {
+                // `me unset --all --key k`, or the key as the only positional
+                let key = match (key_flag, project, key) {
+                    (Some(k), None, None) | (None, Some(k), None) => k,
+                    _ => anyhow::bail!("With --where/--all, name just the key (e.g. --key legacy_field)"),
+                };
+                let (selection, opts) = bulk.split();
+                commands::bulk::run(commands::bulk::BulkOp::Unset { key }, selection, opts)
+            } else {
+                let (Some(project), Some(key)) = (project, key) else {
+                    anyhow::bail!("Usage: me unset <PROJECT> <KEY>");
+                };
+                if key_flag.is_some() || bulk.dry_run || bulk.yes {
+                    anyhow::bail!("--key, --dry-run and --yes only apply with --where or --all");
+                }
+                commands::unset::run(&project, &key)
+            }
+        }
 
         Commands::Rm { project } =>
             commands::delete::run(&project),
 
-        Commands::Keys { action, similar } => {
+        Commands::Keys { action, similar, types } => {
             match action {
                 Some(KeysAction::Rename { old, new, project }) =>
                     commands::keys_rename::run(
@@ -171,8 +499,23 @@ fn main() -> Result<()> {
                         &new,
                         project.as_deref(),
                     ),
+                Some(KeysAction::Values { key, similar }) =>
+                    commands::keys_values::run(&key, similar),
                 None =>
-                    commands::sweep_cmd::run(similar),
+                    commands::sweep_cmd::run(similar, types),
+            }
+        }
+
+        Commands::Values { action } => {
+            match action {
+                ValuesAction::Replace { key, old, new, regex, filters, dry_run } =>
+                    commands::values_replace::run(
+                        &key,
+                        commands::values_replace::Pattern::new(&old, regex)?,
+                        &new,
+                        &filters,
+                        dry_run,
+                    ),
             }
         }
 
@@ -181,5 +524,48 @@ fn main() -> Result<()> {
                 file.as_deref(),
                 as_name.as_deref(),
             ),
+
+        Commands::ImportMd { target, body_key } =>
+            commands::import_md::run(&target, &body_key),
+
+        Commands::ExportVault { dir } =>
+            commands::export_vault::run(&dir),
+
+        Commands::Site { action } => {
+            match action {
+                SiteAction::Build { outdir } =>
+                    commands::site::build(&outdir),
+            }
+        }
+
+        Commands::Diff { a, b, format, order } =>
+            commands::diff_cmd::run(&a, &b, &format, order),
+
+        Commands::Sync { other } =>
+            commands::sync_cmd::run(&other),
+
+        Commands::Doctor { fix } =>
+            commands::doctor::run(fix),
+
+        Commands::Serve { port } =>
+            commands::serve::run(port),
+
+        Commands::Rpc =>
+            commands::rpc::run(),
+
+        Commands::Tui { sort, desc } =>
+            commands::tui::run(sort.as_deref(), desc),
+
+        Commands::Completions { shell } =>
+            commands::completions::run(&shell),
+
+        Commands::Reindex =>
+            commands::reindex::run(),
+
+        Commands::Commit =>
+            commands::commit::run(),
+
+        Commands::Resolve { project, ours, theirs } =>
+            commands::resolve_cmd::run(project.as_deref(), ours, theirs),
     }
 }
@@ -1,7 +1,8 @@
 use anyhow::{Result, Context};
-use std::{fs, path::PathBuf};
+use std::{fs, path::{Path, PathBuf}, sync::OnceLock};
 use uuid::Uuid;
-use crate::global;
+use crate::{config, global};
+use crate::utils::fsio::{self, FileLock};
 
 /// Find membrane root by walking up from CWD
 pub fn find_membrane_root() -> Result<PathBuf> {
@@ -20,11 +21,33 @@ pub fn find_membrane_root() -> Result<PathBuf> {
 }
 
 
+/// Per-invocation brane selection (`--brane` / `MEMBRANE_BRANE`)
+static BRANE_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+pub fn set_brane_override(target: String) {
+    let _ = BRANE_OVERRIDE.set(target);
+}
+
 /// Resolve the workspace root.
 /// Priority:
-/// 1. Globally active workspace (via `me checkout`)
-/// 2. Nearest `.membrane` from cwd (legacy / fallback)
+/// 1. `--brane` flag or `MEMBRANE_BRANE` (path, alias or ID prefix)
+/// 2. Nearest `.membrane` from cwd, when `prefer_local` is set
+/// 3. Globally active workspace (via `me checkout`)
+/// 4. Nearest `.membrane` from cwd (legacy / fallback)
 pub fn resolve_workspace_root() -> Result<PathBuf> {
+    if let Some(target) = BRANE_OVERRIDE.get() {
+        return resolve_brane_target(target);
+    }
+
+    if config::load_global_config()?.prefer_local {
+        if let Ok(root) = find_membrane_root() {
+            return Ok(root);
+        }
+    }
+
+    // a corrupt global index must surface, not silently fall through
+    global::load_global_index()?;
+
     if let Ok(root) = global::active_workspace_root() {
         return Ok(root);
     }
@@ -32,13 +55,85 @@ pub fn resolve_workspace_root() -> Result<PathBuf> {
     find_membrane_root()
 }
 
+/// A brane given explicitly: a directory holding `.membrane`,
+/// or an alias / ID prefix from the global index
+pub fn resolve_brane_target(target: &str) -> Result<PathBuf> {
+    let path = Path::new(target);
+
+    if path.join(".membrane").is_dir() {
+        return Ok(path.to_path_buf());
+    }
+
+    if path.ends_with(".membrane") && path.is_dir() {
+        if let Some(parent) = path.parent() {
+            return Ok(parent.to_path_buf());
+        }
+    }
+
+    let index = global::load_global_index()?;
+    let ws = index
+        .find(target)
+        .with_context(|| format!("'{}' is neither a brane directory nor a known brane", target))?;
+
+    Ok(ws.root.clone())
+}
+
 
-pub fn projects_dir(root: &PathBuf) -> PathBuf {
+pub fn projects_dir(root: &Path) -> PathBuf {
     root.join(".membrane").join("projects")
 }
 
+/// Last-sync snapshots, one file per peer brane
+pub fn sync_dir(root: &Path) -> PathBuf {
+    root.join(".membrane").join("sync")
+}
+
+/// Unresolved sync conflicts, one file per project
+pub fn conflicts_dir(root: &Path) -> PathBuf {
+    root.join(".membrane").join("conflicts")
+}
+
+/// Take the brane lock for a read-modify-write cycle
+pub fn lock_brane(root: &Path) -> Result<FileLock> {
+    fsio::lock_exclusive(&root.join(".membrane").join("lock"))
+}
+
+/// Machine-local state that must not end up in the user's repository
+const LOCAL_IGNORES: &str = "\
+# machine-local state written by `me`
+lock
+global.lock
+git_pending.yaml
+index.json
+.*.tmp
+";
+
+/// Write `.membrane/.gitignore` for the local state files (if missing) and return its path
+pub fn ensure_gitignore(root: &Path) -> Result<PathBuf> {
+    let path = root.join(".membrane").join(".gitignore");
+    if !path.exists() {
+        fsio::write_atomic(&path, LOCAL_IGNORES)?;
+    }
+    Ok(path)
+}
+
+/// Read the stable brane ID from `.membrane/id`
+pub fn read_brane_id(root: &Path) -> Result<String> {
+    let id_path = root.join(".membrane").join("id");
+    let id = fs::read_to_string(&id_path)
+        .with_context(|| format!("Failed to read {}", id_path.display()))?
+        .trim()
+        .to_string();
+
+    if id.is_empty() {
+        anyhow::bail!("Brane at {} has an empty ID", root.display());
+    }
+
+    Ok(id)
+}
+
 /// Initialize a local membrane workspace
-pub fn init_membrane() -> Result<()> {
+pub fn init_membrane(name: Option<&str>) -> Result<()> {
     let root = std::env::current_dir()?;
     let membrane = root.join(".membrane");
     let projects = membrane.join("projects");
@@ -52,14 +147,26 @@ pub fn init_membrane() -> Result<()> {
         fs::read_to_string(&id_path)?.trim().to_string()
     } else {
         let new_id = Uuid::new_v4().to_string();
-        fs::write(&id_path, &new_id)?;
+        fsio::write_atomic(&id_path, &new_id)?;
         new_id
     };
 
     // 🔑 REGISTER THIS WORKSPACE GLOBALLY
-    global::register_workspace(&id, &root)?;
+    global::update_global_index(|index| {
+        index.upsert(&id, &root);
+        if let Some(name) = name {
+            index.set_name(&id, name)?;
+        }
+        Ok(())
+    })?;
 
-    fs::write(membrane.join("config.yaml"), "version: 0.2\n")?;
+    ensure_gitignore(&root)?;
+
+    // never clobber an existing config (it may carry user settings)
+    let config_path = membrane.join("config.yaml");
+    if !config_path.exists() {
+        fsio::write_atomic(&config_path, "version: 0.2\n")?;
+    }
 
     println!("Initialized Membrane in {}", root.display());
     Ok(())
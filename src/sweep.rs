@@ -1,15 +1,30 @@
 use anyhow::Result;
-use std::{collections::HashMap, fs};
+use serde_yaml::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
 use walkdir::WalkDir;
 
+use crate::cache;
 use crate::core::Project;
 
 // ---------- helpers ----------
 
 fn normalize(key: &str) -> String {
     key.to_lowercase()
-        .replace('_', "")
-        .replace('-', "")
+        .replace(['_', '-'], "")
+}
+
+/// YAML type of a value (as in `me keys --types` and `me doctor`); tags are looked through
+pub fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Sequence(_) => "sequence",
+        Value::Mapping(_) => "mapping",
+        Value::Tagged(t) => type_name(&t.value),
+    }
 }
 
 // ---------- public API ----------
@@ -17,46 +32,84 @@ fn normalize(key: &str) -> String {
 pub fn sweep_keys(projects_dir: &std::path::Path) -> Result<HashMap<String, usize>> {
     let mut counts: HashMap<String, usize> = HashMap::new();
 
-    for project in load_projects(projects_dir)? {
-        for key in project.keys() {
-            *counts.entry(key.clone()).or_insert(0) += 1;
+    for keys in all_keys(projects_dir)? {
+        for key in keys {
+            *counts.entry(key).or_insert(0) += 1;
         }
     }
 
     Ok(counts)
 }
 
+/// `sweep_keys` as `(key, count)`, most used first, then by name
+pub fn key_counts(projects_dir: &std::path::Path) -> Result<Vec<(String, usize)>> {
+    let mut counts: Vec<(String, usize)> = sweep_keys(projects_dir)?.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    Ok(counts)
+}
+
 pub fn sweep_similar_keys(
     projects_dir: &std::path::Path,
 ) -> Result<HashMap<String, Vec<String>>> {
     let mut groups: HashMap<String, Vec<String>> = HashMap::new();
 
-    for project in load_projects(projects_dir)? {
-        for key in project.keys() {
-            let norm = normalize(key);
-            groups.entry(norm).or_default().push(key.clone());
+    for keys in all_keys(projects_dir)? {
+        for key in keys {
+            groups.entry(normalize(&key)).or_default().push(key);
         }
     }
 
     Ok(groups)
 }
 
+/// key → YAML type → names of the projects holding that type (sorted)
+pub type KeyTypes = BTreeMap<String, BTreeMap<&'static str, Vec<String>>>;
+
+pub fn sweep_key_types(projects: &[(String, Project)]) -> KeyTypes {
+    let mut types: KeyTypes = BTreeMap::new();
+
+    for (name, project) in projects {
+        for (key, value) in project {
+            types
+                .entry(key.clone())
+                .or_default()
+                .entry(type_name(value))
+                .or_default()
+                .push(name.clone());
+        }
+    }
+
+    for by_type in types.values_mut() {
+        for names in by_type.values_mut() {
+            names.sort();
+        }
+    }
+
+    types
+}
+
 // ---------- internal ----------
 
-fn load_projects(projects_dir: &std::path::Path) -> Result<Vec<Project>> {
-    let mut projects = Vec::new();
+/// Keys of every project: top-level files come from the index cache,
+/// files in subdirectories are read directly (the cache only covers the top level)
+fn all_keys(projects_dir: &std::path::Path) -> Result<Vec<Vec<String>>> {
+    let mut out: Vec<Vec<String>> = cache::refresh(projects_dir)?
+        .entries
+        .into_values()
+        .map(|e| e.keys)
+        .collect();
 
     for entry in WalkDir::new(projects_dir)
-        .min_depth(1)
+        .min_depth(2)
         .into_iter()
         .filter_map(Result::ok)
     {
         if entry.path().extension().and_then(|s| s.to_str()) == Some("yaml") {
             let content = fs::read_to_string(entry.path())?;
             let project: Project = serde_yaml::from_str(&content)?;
-            projects.push(project);
+            out.push(project.into_keys().collect());
         }
     }
 
-    Ok(projects)
+    Ok(out)
 }
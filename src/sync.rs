@@ -0,0 +1,327 @@
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::Project;
+use crate::memfs;
+use crate::utils::fsio::write_atomic;
+
+/// Snapshot of every project as it was after the last sync, keyed by `_id`
+pub type Baseline = IndexMap<String, Project>;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SyncConflict {
+    pub id: String,
+    pub project: String,
+    pub remote_root: PathBuf,
+    pub remote_brane: String,
+    /// Auto-merged project; conflicting keys hold the local value
+    pub merged: Project,
+    /// Remote project at the time of the sync
+    pub remote: Project,
+    pub keys: IndexMap<String, ConflictValues>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConflictValues {
+    pub base: Option<Value>,
+    pub local: Option<Value>,
+    pub remote: Option<Value>,
+}
+
+// ---------- baseline ----------
+
+pub fn baseline_path(root: &Path, peer_id: &str) -> PathBuf {
+    memfs::sync_dir(root).join(format!("{peer_id}.yaml"))
+}
+
+pub fn load_baseline(root: &Path, peer_id: &str) -> Result<Baseline> {
+    let path = baseline_path(root, peer_id);
+
+    if !path.exists() {
+        return Ok(Baseline::new());
+    }
+
+    let content = fs::read_to_string(&path)?;
+    Ok(serde_yaml::from_str(&content)?)
+}
+
+pub fn save_baseline(root: &Path, peer_id: &str, baseline: &Baseline) -> Result<()> {
+    let path = baseline_path(root, peer_id);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_atomic(&path, serde_yaml::to_string(baseline)?)?;
+    Ok(())
+}
+
+// ---------- conflicts ----------
+
+pub fn conflict_path(root: &Path, project: &str) -> PathBuf {
+    memfs::conflicts_dir(root).join(format!("{project}.yaml"))
+}
+
+pub fn save_conflict(root: &Path, conflict: &SyncConflict) -> Result<()> {
+    let path = conflict_path(root, &conflict.project);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_atomic(&path, serde_yaml::to_string(conflict)?)?;
+    Ok(())
+}
+
+pub fn load_conflicts(root: &Path) -> Result<Vec<SyncConflict>> {
+    let dir = memfs::conflicts_dir(root);
+    let mut out = Vec::new();
+
+    if !dir.exists() {
+        return Ok(out);
+    }
+
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+
+        if path.extension().and_then(|s| s.to_str()) != Some("yaml") {
+            continue;
+        }
+
+        let content = fs::read_to_string(&path)?;
+        out.push(serde_yaml::from_str(&content)?);
+    }
+
+    out.sort_by(|a, b| a.project.cmp(&b.project));
+    Ok(out)
+}
+
+// ---------- comparison ----------
+
+/// Content equality, ignoring `_updated` and `name` (file names are per side)
+pub fn same_content(a: &Project, b: &Project) -> bool {
+    let strip = |p: &Project| -> Vec<(String, Value)> {
+        let mut pairs: Vec<(String, Value)> = p
+            .iter()
+            .filter(|(k, _)| k.as_str() != "_updated" && k.as_str() != "name")
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        pairs.sort_by(|x, y| x.0.cmp(&y.0));
+        pairs
+    };
+
+    strip(a) == strip(b)
+}
+
+/// Whether `a` was updated after `b`, by `_updated`
+pub fn newer_than(a: &Project, b: &Project) -> bool {
+    let stamp = |p: &Project| {
+        p.get("_updated")
+            .and_then(|v| v.as_str())
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+    };
+
+    match (stamp(a), stamp(b)) {
+        (Some(a), Some(b)) => a > b,
+        (Some(_), None) => true,
+        _ => false,
+    }
+}
+
+/// What to do with a project present on both sides
+#[derive(Debug, PartialEq, Eq)]
+pub enum Direction {
+    InSync,
+    Push,
+    Pull,
+    /// Both sides changed since the baseline: three-way merge
+    Merge,
+}
+
+/// Changes since the baseline decide the direction; without a baseline
+/// the newer `_updated` wins, and a tie falls back to a merge.
+pub fn direction(base: Option<&Project>, local: &Project, remote: &Project) -> Direction {
+    if same_content(local, remote) {
+        return Direction::InSync;
+    }
+
+    let (local_changed, remote_changed) = match base {
+        Some(b) => (!same_content(b, local), !same_content(b, remote)),
+        None if newer_than(local, remote) => (true, false),
+        None if newer_than(remote, local) => (false, true),
+        None => (true, true),
+    };
+
+    match (local_changed, remote_changed) {
+        (true, false) => Direction::Push,
+        (false, true) => Direction::Pull,
+        _ => Direction::Merge,
+    }
+}
+
+/// A project present on one side only was deleted on the other side
+/// when the baseline had it and it hasn't changed since.
+pub fn deleted_on_other_side(base: Option<&Project>, project: &Project) -> bool {
+    base.is_some_and(|b| same_content(b, project))
+}
+
+/// Per-key three-way merge of user keys.
+/// Conflicting keys keep the local value and are reported separately.
+pub fn merge3(
+    base: &Project,
+    local: &Project,
+    remote: &Project,
+) -> (Project, IndexMap<String, ConflictValues>) {
+    let mut merged: Project = IndexMap::new();
+    let mut conflicts = IndexMap::new();
+
+    // metadata is pinned by the materializer; carry the local copy through
+    for k in ["_id", "_created"] {
+        if let Some(v) = local.get(k) {
+            merged.insert(k.to_string(), v.clone());
+        }
+    }
+
+    let keys = local
+        .keys()
+        .chain(remote.keys())
+        .filter(|k| !k.starts_with('_') && k.as_str() != "name")
+        .cloned()
+        .collect::<indexmap::IndexSet<String>>();
+
+    for key in keys {
+        let b = base.get(&key);
+        let l = local.get(&key);
+        let r = remote.get(&key);
+
+        let pick = if l == r || r == b {
+            l
+        } else if l == b {
+            r
+        } else {
+            conflicts.insert(
+                key.clone(),
+                ConflictValues {
+                    base: b.cloned(),
+                    local: l.cloned(),
+                    remote: r.cloned(),
+                },
+            );
+            l
+        };
+
+        if let Some(v) = pick {
+            merged.insert(key, v.clone());
+        }
+    }
+
+    (merged, conflicts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(pairs: &[(&str, &str)]) -> Project {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), Value::String(v.to_string())))
+            .collect()
+    }
+
+    fn with(base: &Project, pairs: &[(&str, &str)]) -> Project {
+        let mut p = base.clone();
+        for (k, v) in pairs {
+            p.insert(k.to_string(), Value::String(v.to_string()));
+        }
+        p
+    }
+
+    fn base() -> Project {
+        project(&[
+            ("_id", "p1"),
+            ("name", "delta"),
+            ("_updated", "2024-01-01T00:00:00Z"),
+            ("status", "active"),
+            ("owner", "ann"),
+        ])
+    }
+
+    #[test]
+    fn same_content_ignores_name_and_updated() {
+        let renamed = with(&base(), &[("name", "delta2"), ("_updated", "2024-02-01T00:00:00Z")]);
+        assert!(same_content(&base(), &renamed));
+        assert!(!same_content(&base(), &with(&base(), &[("status", "done")])));
+    }
+
+    #[test]
+    fn renamed_on_one_side_stays_in_sync() {
+        let remote = with(&base(), &[("name", "delta2")]);
+        assert_eq!(direction(Some(&base()), &base(), &remote), Direction::InSync);
+        assert_eq!(direction(None, &base(), &remote), Direction::InSync);
+    }
+
+    #[test]
+    fn one_side_changed() {
+        let changed = with(&base(), &[("status", "done")]);
+        assert_eq!(direction(Some(&base()), &changed, &base()), Direction::Push);
+        assert_eq!(direction(Some(&base()), &base(), &changed), Direction::Pull);
+    }
+
+    #[test]
+    fn both_changed_on_different_keys_merges_cleanly() {
+        let local = with(&base(), &[("status", "done")]);
+        let remote = with(&base(), &[("owner", "bob")]);
+        assert_eq!(direction(Some(&base()), &local, &remote), Direction::Merge);
+
+        let (merged, conflicts) = merge3(&base(), &local, &remote);
+        assert!(conflicts.is_empty());
+        assert_eq!(merged["status"], Value::String("done".into()));
+        assert_eq!(merged["owner"], Value::String("bob".into()));
+    }
+
+    #[test]
+    fn both_changed_on_the_same_key_conflicts() {
+        let local = with(&base(), &[("status", "done")]);
+        let remote = with(&base(), &[("status", "paused")]);
+
+        let (merged, conflicts) = merge3(&base(), &local, &remote);
+        assert_eq!(conflicts.keys().collect::<Vec<_>>(), ["status"]);
+        assert_eq!(conflicts["status"].base, Some(Value::String("active".into())));
+        assert_eq!(conflicts["status"].remote, Some(Value::String("paused".into())));
+        // local value is kept until resolved
+        assert_eq!(merged["status"], Value::String("done".into()));
+    }
+
+    #[test]
+    fn merge_applies_key_removals() {
+        let mut remote = base();
+        remote.shift_remove("owner");
+
+        let (merged, conflicts) = merge3(&base(), &base(), &remote);
+        assert!(conflicts.is_empty());
+        assert!(!merged.contains_key("owner"));
+    }
+
+    #[test]
+    fn deletion_vs_edit() {
+        // unchanged since the baseline: the other side's deletion wins
+        assert!(deleted_on_other_side(Some(&base()), &base()));
+        // edited since: keep it
+        let edited = with(&base(), &[("status", "done")]);
+        assert!(!deleted_on_other_side(Some(&base()), &edited));
+        // never synced: it is new, not deleted
+        assert!(!deleted_on_other_side(None, &base()));
+    }
+
+    #[test]
+    fn no_baseline_uses_updated() {
+        let newer = with(&base(), &[("status", "done"), ("_updated", "2024-03-01T00:00:00Z")]);
+        assert_eq!(direction(None, &newer, &base()), Direction::Push);
+        assert_eq!(direction(None, &base(), &newer), Direction::Pull);
+
+        // same timestamp, different content: nothing to go by
+        let tie = with(&base(), &[("status", "done")]);
+        assert_eq!(direction(None, &tie, &base()), Direction::Merge);
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::Result;
+use serde_yaml::Value;
+
+use crate::core::Project;
+
+/// A single project condition: `key`, `key == value` (or `key=value`) or `key != value`.
+/// Values compare against the scalar as written in YAML; for lists,
+/// any item may match.
+#[derive(Debug, Clone)]
+pub struct Filter {
+    pub key: String,
+    pub op: FilterOp,
+}
+
+#[derive(Debug, Clone)]
+pub enum FilterOp {
+    Has,
+    Eq(String),
+    Ne(String),
+}
+
+pub fn parse_filter(input: &str) -> Result<Filter> {
+    let input = input.trim();
+
+    // the operator is the first `=` (with a `!` or `=` around it); anything
+    // after it belongs to the value, even if it contains `==` or `!=`
+    let (key, op) = match input.find('=') {
+        Some(i) if input[..i].ends_with('!') => (&input[..i - 1], FilterOp::Ne(unquote(&input[i + 1..]))),
+        Some(i) if input[i + 1..].starts_with('=') => (&input[..i], FilterOp::Eq(unquote(&input[i + 2..]))),
+        Some(i) => (&input[..i], FilterOp::Eq(unquote(&input[i + 1..]))),
+        None => (input, FilterOp::Has),
+    };
+
+    let key = key.trim();
+    if key.is_empty() {
+        anyhow::bail!("Invalid filter `{}` (expected key, key == value or key != value)", input);
+    }
+
+    Ok(Filter {
+        key: key.to_string(),
+        op,
+    })
+}
+
+/// `"active"` and `'active'` both mean `active`
+fn unquote(value: &str) -> String {
+    let v = value.trim();
+
+    for q in ['"', '\''] {
+        if v.len() >= 2 && v.starts_with(q) && v.ends_with(q) {
+            return v[1..v.len() - 1].to_string();
+        }
+    }
+
+    v.to_string()
+}
+
+impl Filter {
+    pub fn matches(&self, project: &Project) -> bool {
+        let value = project.get(&self.key);
+
+        match &self.op {
+            FilterOp::Has => value.is_some(),
+            FilterOp::Eq(want) => value.is_some_and(|v| value_matches(v, want)),
+            FilterOp::Ne(want) => !value.is_some_and(|v| value_matches(v, want)),
+        }
+    }
+}
+
+/// True when every filter matches
+pub fn matches_all(filters: &[Filter], project: &Project) -> bool {
+    filters.iter().all(|f| f.matches(project))
+}
+
+fn value_matches(value: &Value, want: &str) -> bool {
+    match value {
+        Value::String(s) => s == want,
+        Value::Bool(_) | Value::Number(_) | Value::Null => serde_yaml::to_string(value)
+            .map(|s| s.trim() == want)
+            .unwrap_or(false),
+        Value::Sequence(items) => items.iter().any(|i| value_matches(i, want)),
+        _ => false,
+    }
+}
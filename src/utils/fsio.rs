@@ -0,0 +1,88 @@
+use anyhow::{Context, Result};
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::Write;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a contending `me` process waits for a lock before giving up
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+const LOCK_POLL: Duration = Duration::from_millis(50);
+
+// ------------------------------------------------------------
+// ATOMIC WRITES
+// ------------------------------------------------------------
+
+/// Write to a temp file in the same directory, then rename over `path`.
+/// Readers see either the old or the new contents, never a truncated file.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("Invalid path: {}", path.display()))?
+        .to_string_lossy();
+
+    let tmp = dir.join(format!(".{}.{}.tmp", file_name, std::process::id()));
+
+    let result = (|| -> Result<()> {
+        let mut file = File::create(&tmp)?;
+        file.write_all(contents.as_ref())?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+
+    result.with_context(|| format!("Failed to write {}", path.display()))
+}
+
+// ------------------------------------------------------------
+// ADVISORY LOCKS
+// ------------------------------------------------------------
+
+/// Exclusive advisory lock, released when dropped
+pub struct FileLock {
+    _file: File,
+}
+
+/// Lock `path` exclusively, retrying until `LOCK_TIMEOUT` elapses
+pub fn lock_exclusive(path: &Path) -> Result<FileLock> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+        .with_context(|| format!("Failed to open lock file {}", path.display()))?;
+
+    let start = Instant::now();
+
+    loop {
+        match file.try_lock() {
+            Ok(()) => return Ok(FileLock { _file: file }),
+            Err(TryLockError::WouldBlock) => {
+                if start.elapsed() >= LOCK_TIMEOUT {
+                    anyhow::bail!(
+                        "Another `me` process is holding {} (waited {}s). Try again.",
+                        path.display(),
+                        LOCK_TIMEOUT.as_secs()
+                    );
+                }
+                thread::sleep(LOCK_POLL);
+            }
+            Err(TryLockError::Error(e)) => {
+                return Err(e).with_context(|| format!("Failed to lock {}", path.display()));
+            }
+        }
+    }
+}
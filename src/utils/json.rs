@@ -0,0 +1,11 @@
+use serde_json::{json, Value as Json};
+
+use crate::core::Project;
+
+/// Project as JSON, with its file name alongside the keys (`me serve`, `me rpc`)
+pub fn project_json(name: &str, project: &Project) -> Json {
+    json!({
+        "name": name,
+        "project": serde_json::to_value(project).unwrap_or(Json::Null),
+    })
+}
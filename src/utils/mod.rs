@@ -5,4 +5,8 @@ pub mod banner;
 pub mod input;
 pub mod table;
 pub mod resolve;
-pub mod project_writer;
\ No newline at end of file
+pub mod project_writer;
+pub mod fsio;
+pub mod filter;
+
+pub mod json;
\ No newline at end of file
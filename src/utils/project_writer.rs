@@ -1,14 +1,14 @@
-use std::fs;
 use std::path::Path;
 use anyhow::Result;
 
 use indexmap::IndexMap;
 use serde_yaml::Value;
 use crate::core::Project;
+use crate::utils::fsio::write_atomic;
 use crate::utils::time::now_iso;
 
 // ---- single source of truth
-const RESERVED_KEYS: &[&str] = &[
+pub const RESERVED_KEYS: &[&str] = &[
     "_id",
     "_created",
     "_updated",
@@ -21,7 +21,7 @@ const RESERVED_KEYS: &[&str] = &[
 
 pub fn write_project(path: &Path, project: Project) -> Result<()> {
     let yaml = serde_yaml::to_string(&project)?;
-    fs::write(path, yaml)?;
+    write_atomic(path, yaml)?;
     Ok(())
 }
 
@@ -1,59 +1,69 @@
 use anyhow::Result;
 use std::fs;
 use std::path::Path;
-use serde_yaml::Value;
 
+use crate::cache;
 use crate::core::Project;
+use crate::memfs;
+
+/// Project names become file names under the projects dir; refuse anything
+/// that could point outside it (`../x`, `a/b`, `.hidden`).
+pub fn check_project_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || name.contains(['/', '\\', '\0'])
+        || name.contains("..")
+        || name.starts_with('.')
+    {
+        anyhow::bail!("Invalid project name '{}'", name);
+    }
+    Ok(())
+}
 
 pub fn resolve_project(
     dir: &Path,
     input: &str,
 ) -> Result<(String, Project)> {
-    let mut exact = None;
-    let mut id_matches = Vec::new();
-
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.extension().and_then(|s| s.to_str()) != Some("yaml") {
-            continue;
-        }
-
-        let name = path.file_stem().unwrap().to_string_lossy().to_string();
-        let content = fs::read_to_string(&path)?;
-        let project: Project = serde_yaml::from_str(&content)?;
-
-        // 1. Exact name match
-        if name == input {
-            exact = Some((name, project));
-            break;
-        }
+    check_project_name(input)?;
 
-        // 2. ID prefix match
-        if let Some(Value::String(id)) = project.get("_id") {
-            if id.starts_with(input) {
-                id_matches.push((name, project));
-            }
-        }
+    // 1. Exact name match
+    let exact = dir.join(format!("{input}.yaml"));
+    if exact.is_file() {
+        return Ok((input.to_string(), read_project(&exact)?));
     }
 
-    if let Some(hit) = exact {
-        return Ok(hit);
-    }
+    // 2. ID prefix match (answered by the index cache)
+    let index = cache::refresh(dir)?;
+    let mut id_matches: Vec<(&String, &String)> = index
+        .entries
+        .iter()
+        .filter_map(|(name, e)| e.id.as_ref().map(|id| (name, id)))
+        .filter(|(_, id)| id.starts_with(input))
+        .collect();
 
     match id_matches.len() {
         0 => anyhow::bail!("No project matches '{}'", input),
-        1 => Ok(id_matches.remove(0)),
+        1 => {
+            let (name, _) = id_matches.remove(0);
+            let project = read_project(&dir.join(format!("{name}.yaml")))?;
+            Ok((name.clone(), project))
+        }
         _ => {
             let ids: Vec<String> = id_matches
                 .iter()
-                .filter_map(|(_, p)| p.get("_id"))
-                .filter_map(|v| v.as_str())
-                .map(|s| s[..8].to_string())
+                .map(|(_, id)| id.chars().take(8).collect())
                 .collect();
 
             anyhow::bail!("Ambiguous ID prefix '{}': {:?}", input, ids)
         }
     }
 }
+
+/// `resolve_project` against the projects dir of the brane at `root`
+pub fn resolve_in_brane(root: &Path, input: &str) -> Result<(String, Project)> {
+    resolve_project(&memfs::projects_dir(root), input)
+}
+
+fn read_project(path: &Path) -> Result<Project> {
+    let content = fs::read_to_string(path)?;
+    Ok(serde_yaml::from_str(&content)?)
+}